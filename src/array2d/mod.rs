@@ -0,0 +1,616 @@
+mod render;
+
+use std::io::BufRead;
+
+use crate::{AocError, LineError};
+
+pub use render::Renderer;
+
+/// `(row, col)` offsets of the cells sharing a side with the center.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// `(row, col)` offsets of the cells sharing a side or a corner with the center.
+pub const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// What happens to the neighbors which fall outside of the array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// they are skipped
+    Bounded,
+    /// they are taken from the opposite side, as if the array was a torus
+    Wrap,
+}
+
+/// Coordinates accepted by [`Array2D::get`]: unsigned ones, and signed ones which may
+/// point before the first row or column.
+pub trait GridIndex {
+    /// `(row, col)` if both are non-negative
+    fn to_unsigned(self) -> Option<(usize, usize)>;
+}
+
+macro_rules! impl_grid_index {
+    ($($t:ty),*) => {
+        $(
+            impl GridIndex for ($t, $t) {
+                fn to_unsigned(self) -> Option<(usize, usize)> {
+                    Some((usize::try_from(self.0).ok()?, usize::try_from(self.1).ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_grid_index!(usize, isize, i64, i32);
+
+#[derive(Debug, Clone)]
+pub struct Array2D<T> {
+    data: Vec<T>,
+    cols: usize,
+    rows: usize,
+}
+
+/// The elements don't fit the requested `(rows, cols)` shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeMismatchError {
+    pub expected: (usize, usize),
+    /// number of the elements provided
+    pub len: usize,
+    /// shape of the other array, when two arrays were to be combined
+    pub found: Option<(usize, usize)>,
+}
+
+impl ShapeMismatchError {
+    pub(crate) fn arrays(expected: (usize, usize), found: (usize, usize)) -> Self {
+        Self {
+            expected,
+            len: found.0 * found.1,
+            found: Some(found),
+        }
+    }
+}
+
+impl std::fmt::Display for ShapeMismatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (rows, cols) = self.expected;
+        match self.found {
+            Some((found_rows, found_cols)) => {
+                write!(
+                    f,
+                    "expected {rows}x{cols} array, got {found_rows}x{found_cols}"
+                )
+            }
+            None => write!(
+                f,
+                "expected {rows}x{cols} = {} elements, got {}",
+                rows * cols,
+                self.len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ShapeMismatchError {}
+
+impl<T> Array2D<T> {
+    pub fn from_iter(
+        iter: impl IntoIterator<Item = T>,
+        (rows, cols): (usize, usize),
+    ) -> Result<Self, ShapeMismatchError> {
+        let data = Vec::from_iter(iter);
+        if data.len() != rows * cols {
+            Err(ShapeMismatchError {
+                expected: (rows, cols),
+                len: data.len(),
+                found: None,
+            })
+        } else {
+            Ok(Array2D { data, rows, cols })
+        }
+    }
+
+    /// The same elements in the row-major order, split into rows of the new shape.
+    pub fn reshape(self, shape: (usize, usize)) -> Result<Self, ShapeMismatchError> {
+        Self::from_iter(self.data, shape)
+    }
+
+    /// The elements in the row-major order.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Array of the same shape with `f` applied to every element.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Array2D<U> {
+        Array2D {
+            data: self.data.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Combines the elements at the same positions of two arrays of the same shape.
+    pub fn zip_with<U, V>(
+        &self,
+        other: &Array2D<U>,
+        mut f: impl FnMut(&T, &U) -> V,
+    ) -> Result<Array2D<V>, ShapeMismatchError> {
+        if self.shape() != other.shape() {
+            return Err(ShapeMismatchError::arrays(self.shape(), other.shape()));
+        }
+        let data = std::iter::zip(&self.data, &other.data)
+            .map(|(lhs, rhs)| f(lhs, rhs))
+            .collect();
+        Ok(Array2D {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        })
+    }
+
+    /// Builds the array from lines of text, one cell per character mapped with `cell`.
+    ///
+    /// Every line must have the same number of cells: ragged lines, empty input and
+    /// trailing whitespace are reported as parse errors with the line they were found at.
+    pub fn read_grid<E: Into<AocError>>(
+        mut reader: impl BufRead,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, AocError> {
+        let mut data = Vec::new();
+        let mut cols = None;
+        let mut rows = 0;
+        let mut buffer = String::new();
+        while reader.read_line(&mut buffer)? != 0 {
+            rows += 1;
+            let text = buffer.strip_suffix('\n').unwrap_or(&buffer);
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            let row = parse_row(text, cols, &mut data, &mut cell).map_err(|cause| LineError {
+                line: rows,
+                text: text.to_string(),
+                cause,
+            })?;
+            cols = Some(row);
+            buffer.clear();
+        }
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self { data, rows, cols }),
+            _ => Err(AocError::parse("empty grid")),
+        }
+    }
+
+    /// Same as [`Array2D::read_grid`], for text already in memory.
+    pub fn from_grid_text<E: Into<AocError>>(
+        text: &str,
+        cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, AocError> {
+        Self::read_grid(text.as_bytes(), cell)
+    }
+
+    pub fn from_shape_and_val((rows, cols): (usize, usize), val: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![val; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// The cell at `(x, y)`, or `None` outside of the array.
+    pub fn get(&self, idx: impl GridIndex) -> Option<&T> {
+        let (x, y) = idx.to_unsigned()?;
+        (x < self.rows && y < self.cols).then(|| &self.data[x * self.cols + y])
+    }
+
+    pub fn get_mut(&mut self, idx: impl GridIndex) -> Option<&mut T> {
+        let (x, y) = idx.to_unsigned()?;
+        (x < self.rows && y < self.cols).then(|| &mut self.data[x * self.cols + y])
+    }
+
+    pub fn row(&self, x: usize) -> &[T] {
+        assert!(x <= self.rows);
+        let begin = x * self.cols;
+        let end = begin + self.cols;
+        &self.data[begin..end]
+    }
+
+    pub fn col(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
+        assert!(y < self.cols);
+        self.data.iter().skip(y).step_by(self.cols)
+    }
+
+    pub fn cols(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone,
+    > + ExactSizeIterator
+           + Clone {
+        (0..self.cols).map(move |idx| self.col(idx))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        self.data.chunks(self.cols)
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + '_ {
+        self.data.chunks_mut(self.cols)
+    }
+
+    /// Borrowed view of the whole array, see [`ArrayView2D`] for the transforms.
+    pub fn view(&self) -> ArrayView2D<'_, T> {
+        ArrayView2D {
+            data: &self.data,
+            src_cols: self.cols,
+            offset: 0,
+            row_stride: self.cols as isize,
+            col_stride: 1,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Picture of the array with `cell` drawing every cell, printed with `Display`.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Renderer<'_, T, F> {
+        self.view().render(cell)
+    }
+
+    pub fn iter_indexed(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(idx, val)| ((idx / self.cols, idx % self.cols), val))
+    }
+
+    /// Indices of the in-bounds cells sharing a side with `(x, y)`.
+    pub fn neighbors4(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(idx, &ORTHOGONAL, Edges::Bounded)
+    }
+
+    /// Indices of the in-bounds cells sharing a side or a corner with `(x, y)`.
+    pub fn neighbors8(&self, idx: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors(idx, &ALL_AROUND, Edges::Bounded)
+    }
+
+    /// Indices of `(x, y)` shifted by every offset of the `stencil`, in the stencil order.
+    ///
+    /// With [`Edges::Wrap`] every offset yields an index, on small arrays some of them may repeat.
+    pub fn neighbors<'s>(
+        &self,
+        (x, y): (usize, usize),
+        stencil: &'s [(isize, isize)],
+        edges: Edges,
+    ) -> impl Iterator<Item = (usize, usize)> + 's {
+        let (rows, cols) = (self.rows as isize, self.cols as isize);
+        let (x, y) = (x as isize, y as isize);
+        stencil.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            match edges {
+                Edges::Wrap => Some((x.rem_euclid(rows) as usize, y.rem_euclid(cols) as usize)),
+                Edges::Bounded => {
+                    ((0..rows).contains(&x) && (0..cols).contains(&y)).then_some((x as usize, y as usize))
+                }
+            }
+        })
+    }
+
+    /// Same as [`Array2D::neighbors`], together with the cells.
+    pub fn neighbor_cells<'a>(
+        &'a self,
+        idx: (usize, usize),
+        stencil: &'a [(isize, isize)],
+        edges: Edges,
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        self.neighbors(idx, stencil, edges).map(move |idx| (idx, &self[idx]))
+    }
+}
+
+// appends the cells of one line to `data`, returns the number of cells in it
+fn parse_row<T, E: Into<AocError>>(
+    text: &str,
+    cols: Option<usize>,
+    data: &mut Vec<T>,
+    cell: &mut impl FnMut(char) -> Result<T, E>,
+) -> Result<usize, AocError> {
+    if text.ends_with(char::is_whitespace) {
+        return Err(AocError::parse("trailing whitespace"));
+    }
+    let before = data.len();
+    for c in text.chars() {
+        data.push(cell(c).map_err(Into::into)?);
+    }
+    let row = data.len() - before;
+    match cols {
+        Some(cols) if cols != row => Err(AocError::parse(format!("expected {cols} cells, found {row}"))),
+        _ => Ok(row),
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Array2D<T> {
+    type Output = T;
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.rows);
+        assert!(y < self.cols);
+        &self.data[x * self.cols + y]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Array2D<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.rows);
+        assert!(y < self.cols);
+        &mut self.data[x * self.cols + y]
+    }
+}
+
+/// Borrowed view of an [`Array2D`], possibly transposed, flipped or cropped.
+///
+/// None of the transforms copies the cells: a view only changes how its indices are
+/// mapped to the cells of the array, so an algorithm written for one direction can be run
+/// over every rotation of the array.
+#[derive(Debug)]
+pub struct ArrayView2D<'a, T> {
+    data: &'a [T],
+    // columns of the viewed array, to map the cells back to its indices
+    src_cols: usize,
+    // position of (0, 0) in `data` and the steps to the next row and column
+    offset: usize,
+    row_stride: isize,
+    col_stride: isize,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Clone for ArrayView2D<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArrayView2D<'_, T> {}
+
+impl<'a, T> ArrayView2D<'a, T> {
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn position(&self, (x, y): (usize, usize)) -> usize {
+        let position = self.offset as isize + x as isize * self.row_stride + y as isize * self.col_stride;
+        position as usize
+    }
+
+    /// Index in the viewed array of the cell at `(x, y)` of the view.
+    pub fn source_idx(&self, idx: (usize, usize)) -> (usize, usize) {
+        let position = self.position(idx);
+        (position / self.src_cols, position % self.src_cols)
+    }
+
+    pub fn get(&self, idx: impl GridIndex) -> Option<&'a T> {
+        let (x, y) = idx.to_unsigned()?;
+        (x < self.rows && y < self.cols).then(|| &self.data[self.position((x, y))])
+    }
+
+    pub fn row(&self, x: usize) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone {
+        assert!(x < self.rows);
+        let view = *self;
+        (0..self.cols).map(move |y| &view.data[view.position((x, y))])
+    }
+
+    pub fn rows(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone>
+           + ExactSizeIterator {
+        let view = *self;
+        (0..self.rows).map(move |x| view.row(x))
+    }
+
+    pub fn iter_indexed(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        let view = *self;
+        (0..self.rows).flat_map(move |x| view.row(x).enumerate().map(move |(y, val)| ((x, y), val)))
+    }
+
+    /// Rows become columns: `(x, y)` of the result is `(y, x)` of the view.
+    pub fn transposed(self) -> Self {
+        Self {
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            rows: self.cols,
+            cols: self.rows,
+            ..self
+        }
+    }
+
+    /// Columns in the reverse order.
+    pub fn flipped_horizontally(self) -> Self {
+        if self.cols == 0 {
+            return self;
+        }
+        Self {
+            offset: self.position((0, self.cols - 1)),
+            col_stride: -self.col_stride,
+            ..self
+        }
+    }
+
+    /// Rows in the reverse order.
+    pub fn flipped_vertically(self) -> Self {
+        if self.rows == 0 {
+            return self;
+        }
+        Self {
+            offset: self.position((self.rows - 1, 0)),
+            row_stride: -self.row_stride,
+            ..self
+        }
+    }
+
+    /// Rotated by 90 degrees clockwise: the first column read bottom-up becomes the first row.
+    pub fn rotated_cw(self) -> Self {
+        self.transposed().flipped_horizontally()
+    }
+
+    /// Rotated by 90 degrees counterclockwise: the last column becomes the first row.
+    pub fn rotated_ccw(self) -> Self {
+        self.transposed().flipped_vertically()
+    }
+
+    /// The `shape` cells starting at `(x, y)`, or `None` if they don't fit in the view.
+    pub fn window(self, (x, y): (usize, usize), (rows, cols): (usize, usize)) -> Option<Self> {
+        if x + rows > self.rows || y + cols > self.cols {
+            return None;
+        }
+        let offset = if rows > 0 && cols > 0 { self.position((x, y)) } else { self.offset };
+        Some(Self {
+            offset,
+            rows,
+            cols,
+            ..self
+        })
+    }
+
+    /// Every window of the `shape`, row by row, together with its top-left corner.
+    pub fn windows(self, shape @ (rows, cols): (usize, usize)) -> impl Iterator<Item = ((usize, usize), Self)> {
+        let xs = 0..(self.rows + 1).saturating_sub(rows);
+        xs.flat_map(move |x| {
+            let ys = 0..(self.cols + 1).saturating_sub(cols);
+            ys.filter_map(move |y| Some(((x, y), self.window((x, y), shape)?)))
+        })
+    }
+
+    pub fn render<F: Fn(&T) -> char>(self, cell: F) -> Renderer<'a, T, F> {
+        Renderer::new(self, cell)
+    }
+
+    pub fn to_array(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        Array2D {
+            data: self.iter_indexed().map(|(_, val)| val.clone()).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for ArrayView2D<'_, T> {
+    type Output = T;
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.rows);
+        assert!(y < self.cols);
+        &self.data[self.position((x, y))]
+    }
+}
+
+#[test]
+fn test_read_grid() {
+    let digit = |c: char| c.to_digit(10).ok_or_else(|| AocError::parse(format!("{c:?} is not a digit")));
+
+    let grid = Array2D::from_grid_text("123\n456\n", digit).unwrap();
+    assert_eq!(grid.shape(), (2, 3));
+    assert_eq!(grid[(1, 0)], 4);
+
+    let err = |text| Array2D::from_grid_text(text, digit).unwrap_err().to_string();
+    assert_eq!(err(""), "parse error: empty grid");
+    assert_eq!(err("\n\n"), "parse error: empty grid");
+    assert_eq!(err("123\n45\n"), "parse error: line 2: expected 3 cells, found 2 (in \"45\")");
+    assert_eq!(err("123\n456 \n"), "parse error: line 2: trailing whitespace (in \"456 \")");
+    assert_eq!(err("123\n4x6\n"), "parse error: line 2: 'x' is not a digit (in \"4x6\")");
+}
+
+#[test]
+fn test_neighbors() {
+    let grid = Array2D::from_grid_text("abc\ndef\n", Ok::<_, AocError>).unwrap();
+
+    let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+    assert_eq!(corner, [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+
+    let knight = [(1, 2), (-1, -2)];
+    let cells: String = grid.neighbor_cells((0, 0), &knight, Edges::Bounded).map(|(_, &c)| c).collect();
+    assert_eq!(cells, "f");
+
+    let wrapped: String = grid.neighbor_cells((0, 0), &ORTHOGONAL, Edges::Wrap).map(|(_, &c)| c).collect();
+    assert_eq!(wrapped, "dbdc");
+}
+
+#[test]
+fn test_checked_access() {
+    let mut grid = Array2D::from_shape_and_val((2, 3), 0);
+    *grid.get_mut((1_i64, 2_i64)).unwrap() = 7;
+    assert_eq!(grid.get((1_usize, 2_usize)), Some(&7));
+    assert_eq!(grid.get((-1_isize, 0_isize)), None);
+    assert_eq!(grid.get((0_i32, 3_i32)), None);
+    assert_eq!(grid.get_mut((2_i64, 0_i64)), None);
+}
+
+#[test]
+fn test_views() {
+    let grid = Array2D::from_grid_text("abc\ndef\n", Ok::<_, AocError>).unwrap();
+    let text = |view: ArrayView2D<char>| -> Vec<String> { view.rows().map(|row| row.collect()).collect() };
+
+    assert_eq!(text(grid.view().transposed()), ["ad", "be", "cf"]);
+    assert_eq!(text(grid.view().rotated_cw()), ["da", "eb", "fc"]);
+    assert_eq!(text(grid.view().rotated_ccw()), ["cf", "be", "ad"]);
+    assert_eq!(text(grid.view().flipped_horizontally()), ["cba", "fed"]);
+    assert_eq!(text(grid.view().flipped_vertically()), ["def", "abc"]);
+    assert_eq!(text(grid.view().rotated_cw().rotated_cw().rotated_cw().rotated_cw()), ["abc", "def"]);
+
+    let rotated = grid.view().rotated_cw();
+    assert_eq!(rotated[(2, 0)], 'f');
+    assert_eq!(rotated.source_idx((2, 0)), (1, 2));
+
+    let window = rotated.window((1, 0), (2, 2)).unwrap();
+    assert_eq!(text(window), ["eb", "fc"]);
+    assert_eq!(window.to_array().shape(), (2, 2));
+    assert!(rotated.window((2, 0), (2, 1)).is_none());
+
+    let corners: Vec<_> = grid.view().windows((2, 2)).map(|(idx, view)| (idx, view[(1, 1)])).collect();
+    assert_eq!(corners, [((0, 0), 'e'), ((0, 1), 'f')]);
+}
+
+#[test]
+fn test_render() {
+    let mut grid = Array2D::from_shape_and_val((12, 4), false);
+    grid[(9, 1)] = true;
+    grid[(11, 2)] = true;
+    let cell = |&val: &bool| if val { '#' } else { '.' };
+
+    assert_eq!(grid.render(cell).cropped_to(|&val| val).to_string(), "#.\n..\n.#\n");
+    assert_eq!(
+        grid.render(cell).cropped_to(|&val| val).with_axes().to_string(),
+        "   12\n 9 #.\n10 ..\n11 .#\n"
+    );
+    assert_eq!(grid.render(cell).cropped_to(|_| false).to_string(), "");
+}
+
+#[test]
+fn test_reshape_and_combine() {
+    let grid = Array2D::from_iter(1..=6, (2, 3)).unwrap();
+    let err = Array2D::from_iter(1..=5, (2, 3)).unwrap_err();
+    assert_eq!(err.to_string(), "expected 2x3 = 6 elements, got 5");
+
+    let reshaped = grid.clone().reshape((3, 2)).unwrap();
+    assert_eq!(reshaped.row(2), [5, 6]);
+    assert_eq!(grid.clone().reshape((4, 2)).unwrap_err().len, 6);
+
+    let doubled = grid.map(|&val| val * 2);
+    let sums = grid.zip_with(&doubled, |&lhs, &rhs| lhs + rhs).unwrap();
+    assert_eq!(sums.into_vec(), [3, 6, 9, 12, 15, 18]);
+
+    let err = grid
+        .zip_with(&reshaped, |&lhs, &rhs| lhs + rhs)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "expected 2x3 array, got 3x2");
+}
@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter, Write};
+
+use super::ArrayView2D;
+
+/// Text picture of an array, one char per cell: see [`ArrayView2D::render`].
+pub struct Renderer<'a, T, F> {
+    view: ArrayView2D<'a, T>,
+    cell: F,
+    // index of the top-left cell of the view, for the axis labels
+    origin: (usize, usize),
+    axes: bool,
+}
+
+impl<'a, T, F: Fn(&T) -> char> Renderer<'a, T, F> {
+    pub(super) fn new(view: ArrayView2D<'a, T>, cell: F) -> Self {
+        Self {
+            view,
+            cell,
+            origin: (0, 0),
+            axes: false,
+        }
+    }
+
+    /// Prints the row numbers on the left and the column numbers on top, digits stacked.
+    pub fn with_axes(self) -> Self {
+        Self { axes: true, ..self }
+    }
+
+    /// Leaves only the bounding box of the `interesting` cells; nothing if there are none.
+    pub fn cropped_to(self, interesting: impl Fn(&T) -> bool) -> Self {
+        let mut bbox: Option<((usize, usize), (usize, usize))> = None;
+        for ((x, y), val) in self.view.iter_indexed() {
+            if interesting(val) {
+                let (min, max) = bbox.unwrap_or(((x, y), (x, y)));
+                bbox = Some(((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y))));
+            }
+        }
+        let (min, shape) = match bbox {
+            Some((min, max)) => (min, (max.0 - min.0 + 1, max.1 - min.1 + 1)),
+            None => ((0, 0), (0, 0)),
+        };
+        let view = self.view.window(min, shape).expect("the bounding box is inside the view");
+        Self {
+            view,
+            origin: (self.origin.0 + min.0, self.origin.1 + min.1),
+            ..self
+        }
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Display for Renderer<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (rows, cols) = self.view.shape();
+        let (x0, y0) = self.origin;
+        let row_width = if self.axes && rows > 0 { (x0 + rows - 1).to_string().len() } else { 0 };
+
+        if self.axes && cols > 0 {
+            let labels: Vec<String> = (y0..y0 + cols).map(|y| y.to_string()).collect();
+            let height = labels.last().map_or(0, String::len);
+            for digit in 0..height {
+                write!(f, "{:row_width$} ", "")?;
+                for label in &labels {
+                    // shorter labels are padded on the top
+                    let pad = height - label.len();
+                    f.write_char(if digit < pad { ' ' } else { label.as_bytes()[digit - pad] as char })?;
+                }
+                f.write_char('\n')?;
+            }
+        }
+
+        for (x, row) in self.view.rows().enumerate() {
+            if self.axes {
+                write!(f, "{:>row_width$} ", x0 + x)?;
+            }
+            for val in row {
+                f.write_char((self.cell)(val))?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
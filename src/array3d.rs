@@ -0,0 +1,183 @@
+/// 3D signed `(x, y, z)` index.
+pub type Index3 = (i64, i64, i64);
+
+/// Offsets of the cells sharing a face with the center.
+pub const FACES: [Index3; 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+/// Offsets of the cells sharing a face, an edge or a corner with the center.
+pub const ALL_AROUND: [Index3; 26] = all_around();
+
+const fn all_around() -> [Index3; 26] {
+    let mut offsets = [(0, 0, 0); 26];
+    let mut idx = 0;
+    let mut cell = 0;
+    while cell < 27 {
+        let offset = (cell / 9 - 1, cell / 3 % 3 - 1, cell % 3 - 1);
+        if cell != 13 {
+            offsets[idx] = offset;
+            idx += 1;
+        }
+        cell += 1;
+    }
+    offsets
+}
+
+/// Dense 3D array covering the box from `origin` to `origin + shape` (exclusive).
+///
+/// Indices are signed and absolute, so the cells of a box starting at e.g. `(-1, -1, -1)`
+/// are addressed without shifting the coordinates by hand.
+#[derive(Debug, Clone)]
+pub struct Array3D<T> {
+    data: Vec<T>,
+    shape: (usize, usize, usize),
+    origin: Index3,
+}
+
+impl<T> Array3D<T> {
+    pub fn from_shape_and_val(shape @ (xs, ys, zs): (usize, usize, usize), val: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: vec![val; xs * ys * zs],
+            shape,
+            origin: (0, 0, 0),
+        }
+    }
+
+    /// The box between the `min` and `max` corners, both inclusive.
+    pub fn from_bounds(min: Index3, max: Index3, val: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = |from: i64, to: i64| (to - from + 1).max(0) as usize;
+        let shape = (len(min.0, max.0), len(min.1, max.1), len(min.2, max.2));
+        Self::from_shape_and_val(shape, val).with_origin(min)
+    }
+
+    /// Moves the box so that its first cell is at `origin`.
+    pub fn with_origin(self, origin: Index3) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn shape(&self) -> (usize, usize, usize) {
+        self.shape
+    }
+
+    pub fn origin(&self) -> Index3 {
+        self.origin
+    }
+
+    fn position(&self, (x, y, z): Index3) -> Option<usize> {
+        let (xs, ys, zs) = self.shape;
+        let x = usize::try_from(x - self.origin.0)
+            .ok()
+            .filter(|&x| x < xs)?;
+        let y = usize::try_from(y - self.origin.1)
+            .ok()
+            .filter(|&y| y < ys)?;
+        let z = usize::try_from(z - self.origin.2)
+            .ok()
+            .filter(|&z| z < zs)?;
+        Some((x * ys + y) * zs + z)
+    }
+
+    fn index_at(&self, position: usize) -> Index3 {
+        let (_, ys, zs) = self.shape;
+        let (x, y, z) = (position / (ys * zs), position / zs % ys, position % zs);
+        (
+            self.origin.0 + x as i64,
+            self.origin.1 + y as i64,
+            self.origin.2 + z as i64,
+        )
+    }
+
+    pub fn contains(&self, idx: Index3) -> bool {
+        self.position(idx).is_some()
+    }
+
+    /// The cell at `idx`, or `None` outside of the box.
+    pub fn get(&self, idx: Index3) -> Option<&T> {
+        self.position(idx).map(|pos| &self.data[pos])
+    }
+
+    pub fn get_mut(&mut self, idx: Index3) -> Option<&mut T> {
+        self.position(idx).map(|pos| &mut self.data[pos])
+    }
+
+    pub fn iter_indexed(&self) -> impl DoubleEndedIterator<Item = (Index3, &T)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(pos, val)| (self.index_at(pos), val))
+    }
+
+    /// Indices of the in-bounds cells shifted from `idx` by every offset of the `stencil`.
+    pub fn neighbors<'s>(
+        &self,
+        (x, y, z): Index3,
+        stencil: &'s [Index3],
+    ) -> impl Iterator<Item = Index3> + 's {
+        let (origin, shape) = (self.origin, self.shape);
+        let inside = move |coord: i64, origin: i64, len: usize| {
+            (origin..origin + len as i64).contains(&coord)
+        };
+        stencil
+            .iter()
+            .map(move |&(dx, dy, dz)| (x + dx, y + dy, z + dz))
+            .filter(move |&(x, y, z)| {
+                inside(x, origin.0, shape.0)
+                    && inside(y, origin.1, shape.1)
+                    && inside(z, origin.2, shape.2)
+            })
+    }
+
+    /// Indices of the in-bounds cells sharing a face with `idx`.
+    pub fn neighbors6(&self, idx: Index3) -> impl Iterator<Item = Index3> {
+        self.neighbors(idx, &FACES)
+    }
+
+    /// Indices of the in-bounds cells sharing a face, an edge or a corner with `idx`.
+    pub fn neighbors26(&self, idx: Index3) -> impl Iterator<Item = Index3> {
+        self.neighbors(idx, &ALL_AROUND)
+    }
+}
+
+impl<T> std::ops::Index<Index3> for Array3D<T> {
+    type Output = T;
+    fn index(&self, idx: Index3) -> &Self::Output {
+        let pos = self.position(idx);
+        &self.data[pos.unwrap_or_else(|| panic!("{idx:?} is outside of the array"))]
+    }
+}
+
+impl<T> std::ops::IndexMut<Index3> for Array3D<T> {
+    fn index_mut(&mut self, idx: Index3) -> &mut Self::Output {
+        let pos = self.position(idx);
+        &mut self.data[pos.unwrap_or_else(|| panic!("{idx:?} is outside of the array"))]
+    }
+}
+
+#[test]
+fn test_array3d() {
+    let mut cubes = Array3D::from_bounds((-1, -1, -1), (1, 1, 1), 0);
+    assert_eq!(cubes.shape(), (3, 3, 3));
+    cubes[(1, -1, 0)] = 5;
+    assert_eq!(cubes.get((1, -1, 0)), Some(&5));
+    assert_eq!(cubes.get((2, 0, 0)), None);
+
+    let (idx, _) = cubes.iter_indexed().find(|(_, &val)| val == 5).unwrap();
+    assert_eq!(idx, (1, -1, 0));
+
+    assert_eq!(cubes.neighbors6((0, 0, 0)).count(), 6);
+    assert_eq!(cubes.neighbors6((1, 1, 1)).count(), 3);
+    assert_eq!(cubes.neighbors26((0, 0, 0)).count(), 26);
+    assert_eq!(cubes.neighbors26((-1, -1, -1)).count(), 7);
+}
@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc2022::days;
+
+use crate::fail;
+
+struct Stats {
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+// (day, stage)
+type Key = (u32, &'static str);
+
+// saved summary: `day stage min median max` per line, durations in nanoseconds
+fn load_summary(path: &Path) -> BTreeMap<(u32, String), Duration> {
+    let content = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| fail(&format!("{}: {err}", path.display())));
+    content
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let [day, stage, _min, median, _max] = tokens[..] else {
+                fail(&format!("{}: unexpected line {line:?}", path.display()))
+            };
+            let parse = |s: &str| -> u64 {
+                s.parse()
+                    .unwrap_or_else(|_| fail(&format!("{}: expected int in {line:?}", path.display())))
+            };
+            ((parse(day) as u32, stage.to_string()), Duration::from_nanos(parse(median)))
+        })
+        .collect()
+}
+
+fn save_summary(path: &Path, summary: &BTreeMap<Key, Stats>) -> std::io::Result<()> {
+    use std::io::Write;
+    let mut out = std::io::BufWriter::new(std::fs::File::create(path)?);
+    writeln!(out, "# day stage min median max (ns)")?;
+    for ((day, stage), Stats { min, median, max }) in summary {
+        let (min, median, max) = (min.as_nanos(), median.as_nanos(), max.as_nanos());
+        writeln!(out, "{day} {stage} {min} {median} {max}")?;
+    }
+    out.flush()
+}
+
+// relative change of the median compared to the saved one
+fn change(saved: Duration, now: Duration) -> String {
+    let ratio = now.as_secs_f64() / saved.as_secs_f64().max(f64::MIN_POSITIVE);
+    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+}
+
+// runs every day that has an input `runs` times and prints min/median/max of each stage
+pub fn bench(inputs: &Path, runs: usize, save: Option<&Path>, compare: Option<&Path>) -> bool {
+    let saved = compare.map(load_summary);
+    let mut summary = BTreeMap::<Key, Stats>::new();
+    let mut solved = true;
+
+    for day in days::DAYS {
+        let path = inputs.join(aoc2022::input_file_name(day.day));
+        let Ok(input) = std::fs::read(&path) else {
+            eprintln!("day {:02} ({}): skipped, no {}", day.day, day.name, path.display());
+            continue;
+        };
+
+        let mut samples: [Vec<Duration>; 3] = Default::default();
+        for _ in 0..runs {
+            let run = match day.solve(&mut &input[..], &[1, 2]) {
+                Ok(run) => run,
+                Err(err) => {
+                    eprintln!("day {:02} ({}): {err}", day.day, day.name);
+                    solved = false;
+                    break;
+                }
+            };
+            samples[0].push(run.parse_elapsed);
+            for part in run.parts {
+                if let Err(err) = part.answer {
+                    eprintln!("day {:02} ({}) part {}: {err}", day.day, day.name, part.part);
+                    solved = false;
+                }
+                samples[part.part as usize].push(part.elapsed);
+            }
+        }
+
+        for (stage, samples) in ["parse", "part1", "part2"].into_iter().zip(samples) {
+            if !samples.is_empty() {
+                summary.insert((day.day, stage), Stats::new(samples));
+            }
+        }
+    }
+
+    let vs_saved_header = if saved.is_some() { "   vs saved" } else { "" };
+    println!(
+        "day {:<6} {:>12} {:>12} {:>12}{vs_saved_header}",
+        "stage", "min", "median", "max"
+    );
+    for (&(day, stage), stats) in &summary {
+        let vs_saved = saved
+            .as_ref()
+            .map(|saved| match saved.get(&(day, stage.to_string())) {
+                Some(&median) => format!("   {:>8}", change(median, stats.median)),
+                None => format!("   {:>8}", "new"),
+            })
+            .unwrap_or_default();
+        println!(
+            " {day:02} {stage:<6} {:>12} {:>12} {:>12}{vs_saved}",
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.max),
+        );
+    }
+
+    if let Some(path) = save {
+        if let Err(err) = save_summary(path, &summary) {
+            fail(&format!("{}: {err}", path.display()))
+        }
+    }
+
+    solved
+}
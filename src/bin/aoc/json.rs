@@ -0,0 +1,76 @@
+// JSON Lines output: one object per solved part, or per day which failed to be parsed
+
+use aoc2022::days::{Day, PartRun, Run};
+use aoc2022::{AocError, Answer};
+
+fn string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if u32::from(c) < 0x20 => escaped.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn answer(answer: &Answer) -> String {
+    match answer {
+        Answer::Int(val) => val.to_string(),
+        Answer::Text(text) => string(text),
+    }
+}
+
+fn object<'a>(fields: impl IntoIterator<Item = (&'a str, String)>) -> String {
+    let fields: Vec<_> = fields
+        .into_iter()
+        .map(|(name, value)| format!("{}:{value}", string(name)))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+fn day_fields(day: &Day) -> [(&'static str, String); 2] {
+    [("day", day.day.to_string()), ("name", string(day.name))]
+}
+
+// prints a record per part and returns whether every part was solved
+pub fn print_run(day: &Day, run: &Run) -> bool {
+    let mut solved = true;
+    for PartRun { part, answer: result, elapsed, diagnostics } in &run.parts {
+        let mut fields = Vec::from(day_fields(day));
+        fields.push(("part", part.to_string()));
+        match result {
+            Ok(result) => fields.push(("answer", answer(result))),
+            Err(err) => {
+                fields.push(("error", string(&err.to_string())));
+                solved = false;
+            }
+        }
+        fields.push(("elapsed_ns", elapsed.as_nanos().to_string()));
+        fields.push(("parse_elapsed_ns", run.parse_elapsed.as_nanos().to_string()));
+        if !diagnostics.is_empty() {
+            let diagnostics = diagnostics.iter().map(|(name, value)| (*name, answer(value)));
+            fields.push(("diagnostics", object(diagnostics)));
+        }
+        println!("{}", object(fields));
+    }
+    solved
+}
+
+pub fn print_error(day: &Day, err: &AocError) {
+    let mut fields = Vec::from(day_fields(day));
+    fields.push(("error", string(&err.to_string())));
+    println!("{}", object(fields));
+}
+
+#[test]
+fn test_string_escaping() {
+    assert_eq!(string("a\"b\\c\n\u{1}"), r#""a\"b\\c\n\u0001""#);
+}
@@ -0,0 +1,185 @@
+mod bench;
+mod json;
+mod verify;
+
+use aoc2022::days::{self, Day, PartRun, Run};
+use std::path::Path;
+
+const USAGE: &str = "usage:
+    aoc <day> [input] [--part 1|2|both] [--output text|json]
+                                           solve one day, reading <input> (`-` for stdin, inputs/dayNN.txt by default)
+    aoc all [inputs-dir] [--part 1|2|both] [--output text|json]
+                                           solve every registered day, reading <inputs-dir>/dayNN.txt,
+                                           --output json prints a JSON object per line for each part
+    aoc verify [inputs-dir] [--ledger <file>] [--record]
+                                           solve every day and compare with the answers in the ledger
+                                           (answers.txt by default), --record saves the new answers
+    aoc bench [inputs-dir] [--runs N] [--save <file>] [--compare <file>]
+                                           solve every day N times (10 by default) and print min/median/max
+                                           of each stage, --save stores them to be compared with later";
+
+#[derive(Debug, Clone, Copy)]
+enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "1" => Some(Self::One),
+            "2" => Some(Self::Two),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    fn numbers(self) -> &'static [u32] {
+        match self {
+            Self::One => &[1],
+            Self::Two => &[2],
+            Self::Both => &[1, 2],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Output {
+    Text,
+    Json,
+}
+
+impl Output {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+// prints the answers and returns whether every part was solved
+fn print_run(day: &Day, run: &Run) -> bool {
+    println!("day {:02} {}: parsed in {:.2?}", day.day, day.name, run.parse_elapsed);
+    let mut solved = true;
+    for PartRun { part, answer, elapsed, diagnostics } in &run.parts {
+        match answer {
+            Ok(answer) => {
+                let answer = answer.to_string();
+                if answer.contains('\n') {
+                    println!("  part {part} ({elapsed:.2?}):\n{answer}")
+                } else {
+                    println!("  part {part}: {answer} ({elapsed:.2?})")
+                }
+            }
+            Err(err) => {
+                eprintln!("day {:02} ({}) part {part}: {err}", day.day, day.name);
+                solved = false;
+            }
+        }
+        for (name, value) in diagnostics {
+            println!("    {name}: {value}");
+        }
+    }
+    solved
+}
+
+pub(crate) fn fail(msg: &str) -> ! {
+    eprintln!("{msg}");
+    std::process::exit(2)
+}
+
+fn run_day(day: &Day, input: Option<&Path>, parts: Parts, output: Output) -> bool {
+    let run = aoc2022::open_input(day.day, input)
+        .and_then(|mut reader| day.solve(&mut reader, parts.numbers()));
+    match (run, output) {
+        (Ok(run), Output::Text) => print_run(day, &run),
+        (Ok(run), Output::Json) => json::print_run(day, &run),
+        (Err(err), Output::Text) => {
+            eprintln!("day {:02} ({}): {err}", day.day, day.name);
+            false
+        }
+        (Err(err), Output::Json) => {
+            json::print_error(day, &err);
+            false
+        }
+    }
+}
+
+fn run_one(day: u32, input: Option<&str>, parts: Parts, output: Output) -> bool {
+    let day = days::find(day).unwrap_or_else(|| fail(&format!("day {day} is not registered")));
+    run_day(day, input.map(Path::new), parts, output)
+}
+
+fn run_all(inputs: &Path, parts: Parts, output: Output) -> bool {
+    let mut solved = true;
+    for day in days::DAYS {
+        let path = inputs.join(aoc2022::input_file_name(day.day));
+        solved &= run_day(day, Some(&path), parts, output);
+    }
+    solved
+}
+
+// removes `name value` from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let pos = args.iter().position(|arg| arg == name)?;
+    if pos + 1 >= args.len() {
+        fail(USAGE)
+    }
+    let value = args.remove(pos + 1);
+    args.remove(pos);
+    Some(value)
+}
+
+// removes `name` from the arguments
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let pos = args.iter().position(|arg| arg == name);
+    pos.map(|pos| args.remove(pos)).is_some()
+}
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    let parts = take_option(&mut args, "--part")
+        .map(|value| Parts::parse(&value).unwrap_or_else(|| fail(USAGE)))
+        .unwrap_or(Parts::Both);
+    let output = take_option(&mut args, "--output")
+        .map(|value| Output::parse(&value).unwrap_or_else(|| fail(USAGE)))
+        .unwrap_or(Output::Text);
+    let ledger = take_option(&mut args, "--ledger");
+    let ledger = Path::new(ledger.as_deref().unwrap_or(aoc2022::ledger::LEDGER_FILE));
+    let record = take_flag(&mut args, "--record");
+    let runs = take_option(&mut args, "--runs")
+        .map(|value| {
+            let runs = value.parse().ok().filter(|&runs| runs > 0);
+            runs.unwrap_or_else(|| fail(USAGE))
+        })
+        .unwrap_or(10);
+    let save = take_option(&mut args, "--save");
+    let compare = take_option(&mut args, "--compare");
+    let (save, compare) = (save.as_deref().map(Path::new), compare.as_deref().map(Path::new));
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let solved = match args[..] {
+        ["bench"] => bench::bench(aoc2022::INPUTS_DIR.as_ref(), runs, save, compare),
+        ["bench", dir] => bench::bench(dir.as_ref(), runs, save, compare),
+        ["verify"] => verify::verify(aoc2022::INPUTS_DIR.as_ref(), ledger, record),
+        ["verify", dir] => verify::verify(dir.as_ref(), ledger, record),
+        ["all"] => run_all(aoc2022::INPUTS_DIR.as_ref(), parts, output),
+        ["all", dir] => run_all(dir.as_ref(), parts, output),
+        [day] => {
+            let day = day.parse().unwrap_or_else(|_| fail(USAGE));
+            run_one(day, None, parts, output)
+        }
+        [day, input] => {
+            let day = day.parse().unwrap_or_else(|_| fail(USAGE));
+            run_one(day, Some(input), parts, output)
+        }
+        _ => fail(USAGE),
+    };
+    if !solved {
+        std::process::exit(1)
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use aoc2022::days;
+use aoc2022::ledger::Ledger;
+
+use crate::fail;
+
+fn load_ledger(path: &Path) -> Ledger {
+    let parsed = match std::fs::File::open(path) {
+        Ok(file) => Ledger::parse(std::io::BufReader::new(file)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Ledger::default()),
+        Err(err) => Err(err.into()),
+    };
+    parsed.unwrap_or_else(|err| fail(&format!("{}: {err}", path.display())))
+}
+
+// solves every day which has an input and compares the answers with the ledger:
+// returns false on mismatches and on answers which are no longer produced
+pub fn verify(inputs: &Path, ledger_path: &Path, record: bool) -> bool {
+    let mut ledger = load_ledger(ledger_path);
+    let mut verified = true;
+    let mut new_answers = 0;
+
+    for day in days::DAYS {
+        let path = inputs.join(aoc2022::input_file_name(day.day));
+        let run = path.exists().then(|| {
+            aoc2022::open_input(day.day, Some(&path))
+                .and_then(|mut reader| day.solve(&mut reader, &[1, 2]))
+        });
+
+        for part in [1, 2] {
+            let produced = match &run {
+                None => Err("no input".to_string()),
+                Some(Err(err)) => Err(err.to_string()),
+                Some(Ok(run)) => {
+                    let part_run = run.parts.iter().find(|p| p.part == part);
+                    let part_run = part_run.expect("both parts are run");
+                    let answer = part_run.answer.as_ref();
+                    answer.map(ToString::to_string).map_err(ToString::to_string)
+                }
+            };
+
+            let prefix = format!("day {:02} part {part}", day.day);
+            match (ledger.get(day.day, part), produced) {
+                (Some(expected), Ok(answer)) if expected == answer => println!("{prefix}: ok"),
+                (Some(expected), Ok(answer)) => {
+                    println!("{prefix}: MISMATCH: expected {expected:?}, got {answer:?}");
+                    verified = false;
+                }
+                (Some(expected), Err(err)) => {
+                    println!("{prefix}: MISSING: expected {expected:?}, but {err}");
+                    verified = false;
+                }
+                (None, Ok(answer)) => {
+                    println!("{prefix}: new: {answer:?}");
+                    new_answers += 1;
+                    if record {
+                        ledger.insert(day.day, part, answer);
+                    }
+                }
+                (None, Err(err)) if run.is_some() => {
+                    println!("{prefix}: failed: {err}");
+                    verified = false;
+                }
+                // neither input nor answer: nothing to verify
+                (None, Err(_)) => (),
+            }
+        }
+    }
+
+    if record && new_answers > 0 {
+        let saved = std::fs::File::create(ledger_path)
+            .and_then(|file| ledger.write(std::io::BufWriter::new(file)));
+        if let Err(err) = saved {
+            fail(&format!("{}: {err}", ledger_path.display()))
+        }
+        println!("recorded {new_answers} new answers in {}", ledger_path.display());
+    }
+
+    verified
+}
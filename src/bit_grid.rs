@@ -0,0 +1,151 @@
+use crate::ShapeMismatchError;
+
+const BITS: usize = u64::BITS as usize;
+
+/// 2D grid of flags packed into one bit per cell, indexed by `(row, col)` like [`crate::Array2D`].
+///
+/// Meant for visited and occupancy maps: `insert` is a test-and-set, so a search can mark
+/// a cell and learn whether it was seen before in one call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    rows: usize,
+    cols: usize,
+}
+
+impl BitGrid {
+    /// Grid of the `shape` with every cell unset.
+    pub fn new((rows, cols): (usize, usize)) -> Self {
+        Self {
+            words: vec![0; (rows * cols).div_ceil(BITS)],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    // word and the mask of the bit of `(x, y)`
+    fn bit(&self, (x, y): (usize, usize)) -> (usize, u64) {
+        assert!(x < self.rows);
+        assert!(y < self.cols);
+        let idx = x * self.cols + y;
+        (idx / BITS, 1 << (idx % BITS))
+    }
+
+    pub fn get(&self, idx: (usize, usize)) -> bool {
+        let (word, mask) = self.bit(idx);
+        self.words[word] & mask != 0
+    }
+
+    pub fn set(&mut self, idx: (usize, usize), val: bool) {
+        if val {
+            self.insert(idx);
+        } else {
+            self.remove(idx);
+        }
+    }
+
+    /// Sets the cell, returns whether it was unset before.
+    pub fn insert(&mut self, idx: (usize, usize)) -> bool {
+        let (word, mask) = self.bit(idx);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        !was_set
+    }
+
+    /// Unsets the cell, returns whether it was set before.
+    pub fn remove(&mut self, idx: (usize, usize)) -> bool {
+        let (word, mask) = self.bit(idx);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Number of the set cells.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Sets every cell which is set in `other`.
+    pub fn union_with(&mut self, other: &Self) -> Result<(), ShapeMismatchError> {
+        self.combine(other, |lhs, rhs| lhs | rhs)
+    }
+
+    /// Unsets every cell which is not set in `other`.
+    pub fn intersect_with(&mut self, other: &Self) -> Result<(), ShapeMismatchError> {
+        self.combine(other, |lhs, rhs| lhs & rhs)
+    }
+
+    fn combine(
+        &mut self,
+        other: &Self,
+        op: impl Fn(u64, u64) -> u64,
+    ) -> Result<(), ShapeMismatchError> {
+        if self.shape() != other.shape() {
+            return Err(ShapeMismatchError::arrays(self.shape(), other.shape()));
+        }
+        for (lhs, &rhs) in self.words.iter_mut().zip(&other.words) {
+            *lhs = op(*lhs, rhs);
+        }
+        Ok(())
+    }
+
+    /// Indices of the set cells, row by row.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(word_idx, &word)| {
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    let idx = word_idx * BITS + bit;
+                    Some((idx / self.cols, idx % self.cols))
+                })
+            })
+    }
+}
+
+#[test]
+fn test_bit_grid() {
+    let mut grid = BitGrid::new((3, 50));
+    assert!(grid.insert((0, 1)));
+    assert!(!grid.insert((0, 1)));
+    assert!(grid.insert((2, 49)));
+    grid.set((1, 20), true);
+    assert!(grid.get((1, 20)));
+    assert!(!grid.get((1, 21)));
+    assert_eq!(grid.count(), 3);
+    assert_eq!(
+        grid.iter_ones().collect::<Vec<_>>(),
+        [(0, 1), (1, 20), (2, 49)]
+    );
+
+    let mut other = BitGrid::new((3, 50));
+    other.insert((1, 20));
+    other.insert((1, 0));
+
+    let mut union = grid.clone();
+    union.union_with(&other).unwrap();
+    assert_eq!(union.count(), 4);
+    grid.intersect_with(&other).unwrap();
+    assert_eq!(grid.iter_ones().collect::<Vec<_>>(), [(1, 20)]);
+
+    assert!(grid.remove((1, 20)));
+    assert_eq!(grid.count(), 0);
+    assert!(grid.union_with(&BitGrid::new((50, 3))).is_err());
+}
@@ -0,0 +1,103 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub};
+
+/// Repetition found in the states of a simulation, together with a quantity it accumulates.
+///
+/// After `start` steps the states repeat every `len` steps, and each round adds `delta`
+/// to the quantity.
+#[derive(Debug, Clone)]
+pub struct Cycle<Q> {
+    pub start: usize,
+    pub len: usize,
+    pub delta: Q,
+    // the quantity after each of the first `start + len` steps
+    values: Vec<Q>,
+}
+
+impl<Q> Cycle<Q>
+where
+    Q: Copy + Add<Output = Q> + Mul<Output = Q> + TryFrom<usize>,
+    <Q as TryFrom<usize>>::Error: Debug,
+{
+    /// The quantity after `steps` steps, however many of them there are.
+    pub fn extrapolate(&self, steps: usize) -> Q {
+        if let Some(&value) = self.values.get(steps) {
+            return value;
+        }
+        let offset = steps - self.start;
+        let rounds =
+            Q::try_from(offset / self.len).expect("the number of rounds fits the quantity");
+        self.values[self.start + offset % self.len] + self.delta * rounds
+    }
+}
+
+/// Steps the `state` until its fingerprint repeats.
+///
+/// `step` advances the state and returns the quantity accumulated so far, which starts at
+/// its default value. Two states with the same fingerprint must go on the same way and add
+/// the same to the quantity, otherwise the found cycle means nothing. Gives up with `None`
+/// if no fingerprint repeats within `limit` steps.
+pub fn find_cycle<S, K, Q>(
+    mut state: S,
+    limit: usize,
+    mut fingerprint: impl FnMut(&S) -> K,
+    mut step: impl FnMut(&mut S) -> Q,
+) -> Option<Cycle<Q>>
+where
+    K: Eq + Hash,
+    Q: Copy + Default + Sub<Output = Q>,
+{
+    let mut seen = HashMap::new();
+    let mut values = vec![Q::default()];
+    loop {
+        let steps = values.len() - 1;
+        match seen.entry(fingerprint(&state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Some(Cycle {
+                    start,
+                    len: steps - start,
+                    delta: values[steps] - values[start],
+                    values,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+        if steps == limit {
+            return None;
+        }
+        values.push(step(&mut state));
+    }
+}
+
+#[test]
+fn test_cycle() {
+    // the state walks 0, 1, 2, 3, 4, 5, 6, 3, 4, ... and the quantity sums the visited states
+    let next = |x: i64| if x == 6 { 3 } else { x + 1 };
+    let step = |(x, sum): &mut (i64, i64)| {
+        *x = next(*x);
+        *sum += *x;
+        *sum
+    };
+    assert!(find_cycle((0, 0), 6, |&(x, _)| x, step).is_none());
+
+    let cycle = find_cycle((0, 0), 7, |&(x, _)| x, step).unwrap();
+    assert_eq!((cycle.start, cycle.len, cycle.delta), (3, 4, 3 + 4 + 5 + 6));
+
+    let (mut x, mut sum) = (0, 0);
+    for steps in 1..100 {
+        x = next(x);
+        sum += x;
+        assert_eq!(cycle.extrapolate(steps), sum);
+    }
+    // 1 + 2 + 3 + 4 and then (10^12 - 4) / 4 rounds
+    assert_eq!(
+        cycle.extrapolate(1_000_000_000_000),
+        10 + 18 * 249_999_999_999
+    );
+}
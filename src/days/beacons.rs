@@ -0,0 +1,103 @@
+use crate::{AocError, Answer, IntervalSet, Vec2};
+
+#[derive(Debug)]
+struct Sensor {
+    pos: Vec2<i64>,
+    nearest_distance: i64,
+}
+
+fn parse_line(line: &str) -> Result<(Sensor, Vec2<i64>), AocError> {
+    let (sensor, beacon) = line
+        .split_once(':')
+        .ok_or_else(|| AocError::parse("expected :"))?;
+    let sensor = sensor.trim();
+    let beacon = beacon.trim();
+
+    let extract = |s: &str| -> Result<Vec2<i64>, AocError> {
+        let mut tokens = s.split('=');
+        let _ = tokens.next();
+        let (x, _) = tokens
+            .next()
+            .map(str::trim)
+            .and_then(|s| s.split_once(','))
+            .ok_or_else(|| AocError::parse("x should be there"))?;
+        let y = tokens.next().ok_or_else(|| AocError::parse("expected y"))?;
+        Ok(Vec2::new(x.parse()?, y.parse()?))
+    };
+
+    let beacon = extract(beacon)?;
+    let pos = extract(sensor)?;
+
+    Ok((
+        Sensor {
+            pos,
+            nearest_distance: pos.manhattan_distance(beacon),
+        },
+        beacon,
+    ))
+}
+
+impl Sensor {
+    // positions covered at `y`, empty if the sensor doesn't reach it
+    fn get_interval_at_y(&self, y: i64) -> std::ops::Range<i64> {
+        let Vec2 { x, y: sy } = self.pos;
+        let d = (sy - y).abs();
+
+        if d > self.nearest_distance {
+            return x..x;
+        }
+
+        let ofs = self.nearest_distance - d;
+        x - ofs..x + ofs + 1
+    }
+}
+
+fn covered_at(sensors: &[Sensor], y: i64) -> IntervalSet<i64> {
+    sensors.iter().map(|s| s.get_interval_at_y(y)).collect()
+}
+
+pub struct Report {
+    sensors: Vec<Sensor>,
+    beacons: Vec<Vec2<i64>>,
+}
+
+pub fn count_covered_at(report: &Report, y: i64) -> Result<Answer, AocError> {
+    let mut covered = covered_at(&report.sensors, y);
+    for beacon in &report.beacons {
+        if beacon.y == y {
+            covered.remove(beacon.x..beacon.x + 1);
+        }
+    }
+    Ok(covered.covered_len().into())
+}
+
+pub fn find_tuning_frequency(report: &Report, limit: i64) -> Result<Answer, AocError> {
+    const FREQUENCY_MULT: i64 = 4_000_000;
+
+    let pos = (0..=limit)
+        .find_map(|y| covered_at(&report.sensors, y).first_gap(0..limit + 1).zip(Some(y)))
+        .map(|(x, y)| x * FREQUENCY_MULT + y)
+        .ok_or_else(|| AocError::unsolvable("expected uncovered position"))?;
+
+    Ok(pos.into())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Report;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        let lines = crate::try_parse_line_by_line(reader, parse_line).collect::<Result<Vec<_>, _>>()?;
+        let (sensors, beacons) = lines.into_iter().unzip();
+        Ok(Report { sensors, beacons })
+    }
+
+    fn part1(report: &Self::Input) -> Result<Answer, AocError> {
+        count_covered_at(report, 2_000_000)
+    }
+
+    fn part2(report: &Self::Input) -> Result<Answer, AocError> {
+        find_tuning_frequency(report, 4_000_000)
+    }
+}
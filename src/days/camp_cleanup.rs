@@ -0,0 +1,61 @@
+use std::io::BufRead;
+
+use std::ops::RangeInclusive;
+
+use crate::{AocError, Answer};
+
+fn parse_range(range: &str) -> Result<RangeInclusive<i64>, AocError> {
+    let Some((begin, end)) = range.split_once("-") else {
+        return Err(AocError::parse("expected range in x-y format"));
+    };
+    Ok(RangeInclusive::new(begin.parse()?, end.parse()?))
+}
+
+fn contains<Idx: PartialOrd<Idx>>(this: &RangeInclusive<Idx>, other: &RangeInclusive<Idx>) -> bool {
+    this.contains(other.start()) && this.contains(other.end())
+}
+
+fn overlaps<Idx: PartialOrd<Idx>>(first: &RangeInclusive<Idx>, second: &RangeInclusive<Idx>) -> bool {
+     first.contains(second.start()) 
+    || first.contains(second.end()) 
+    || second.contains(first.start()) 
+    || second.contains(first.end())
+}
+
+type Assignment = (RangeInclusive<i64>, RangeInclusive<i64>);
+
+fn count_pairs(
+    pairs: &[Assignment],
+    mut pred: impl FnMut(&RangeInclusive<i64>, &RangeInclusive<i64>) -> bool,
+) -> Answer {
+    let needle_cnt = pairs.iter().filter(|(first, second)| {
+        pred(first, second)
+    }).count();
+    needle_cnt.into()
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<Assignment>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, AocError> {
+        let pairs = crate::try_parse_line_by_line(reader, |line| {
+            let (first, second) = line
+                .split_once(",")
+                .ok_or_else(|| AocError::parse("expected pair of ranges"))?;
+            Ok::<_, AocError>((parse_range(first)?, parse_range(second)?))
+        });
+        Ok(pairs.collect::<Result<_, _>>()?)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Answer, AocError> {
+        Ok(count_pairs(pairs, |first, second| {
+            contains(first, second) || contains(second, first)
+        }))
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Answer, AocError> {
+        Ok(count_pairs(pairs, overlaps))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{either::Either, try_parse_line_by_line, AocError, Answer};
+
+#[derive(Clone, Copy)]
+pub enum Command {
+    Noop,
+    Add(i64),
+}
+
+fn parse_command(line: &str) -> Result<impl Iterator<Item = Command>, AocError> {
+    let mut tokens = line.split_whitespace();
+    let cmd = tokens
+        .next()
+        .ok_or_else(|| AocError::parse("command expected"))?;
+    match cmd {
+        "noop" => Ok(Either::Left(std::iter::once(Command::Noop))),
+        "addx" => {
+            let arg: i64 = tokens
+                .next()
+                .ok_or_else(|| AocError::parse("expected add arg"))?
+                .parse()?;
+            Ok(Either::Right([Command::Noop, Command::Add(arg)].into_iter()))
+        }
+        x => Err(AocError::parse(format!("unexpected input: {x}"))),
+    }
+}
+
+pub struct Register(i64);
+
+impl Register {
+    fn exec(&mut self, cmd: Command) -> &mut Self {
+        if let Command::Add(val) = cmd {
+            self.0 += val;
+        }
+        self
+    }
+
+    fn strenght(&self, cycle: usize) -> i64 {
+        cycle as i64 * self.0
+    }
+
+    fn draw(&self, cycle: usize) -> char {
+        if self.0.abs_diff((cycle % 40) as i64) <= 1 {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    // one command per cycle: `addx` is expanded into noop + add
+    type Input = Vec<Command>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        let commands = try_parse_line_by_line(reader, parse_command).collect::<Result<Vec<_>, _>>()?;
+        Ok(commands.into_iter().flatten().collect())
+    }
+
+    fn part1(commands: &Self::Input) -> Result<Answer, AocError> {
+        let interesting_cycles = [20, 60, 100, 140, 180, 220];
+        let mut accum = 0;
+        let mut reg = Register(1);
+        let _ = std::iter::zip(1.., commands.iter().copied()).fold(
+            &mut reg,
+            |reg, (cycle, cmd)| {
+                if interesting_cycles.contains(&cycle) {
+                    accum += reg.strenght(cycle);
+                }
+                reg.exec(cmd)
+            },
+        );
+        Ok(accum.into())
+    }
+
+    fn part2(commands: &Self::Input) -> Result<Answer, AocError> {
+        let mut reg = Register(1);
+        let mut symbols = std::iter::zip(
+            0..240,
+            commands
+                .iter()
+                .copied()
+                .chain(std::iter::repeat(Command::Noop)),
+        )
+        .map(|(idx, cmd)| {
+            let symbol = reg.draw(idx);
+            reg.exec(cmd);
+            symbol
+        });
+
+        let symbol_line = std::iter::from_fn(move || {
+            let line: String = symbols.by_ref().take(40).collect();
+            (!line.is_empty()).then_some(line)
+        });
+
+        Ok(symbol_line.collect::<Vec<_>>().join("\n").into())
+    }
+}
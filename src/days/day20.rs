@@ -1,4 +1,11 @@
+use crate::{AocError, Answer};
+
 fn rotate<T>(buf: &mut [T], from: usize, shift: i64) {
+    // a single number has nowhere to move
+    if buf.len() <= 1 {
+        return;
+    }
+
     let shift = (shift.abs() % (buf.len() - 1) as i64) * shift.signum();
 
     if shift == 0 {
@@ -30,7 +37,7 @@ fn mixin(arr: Vec<i64>, count: usize) -> Vec<i64> {
     for _ in 0..count {
         let mut curr_idx = 0_usize;
         let mut cur_number = 0_usize;
-        while let Some(pos) = (&buffer[curr_idx..])
+        while let Some(pos) = buffer[curr_idx..]
             .iter()
             .chain(&buffer[..curr_idx])
             .position(|v| cur_number == v.index)
@@ -52,15 +59,14 @@ fn mixin(arr: Vec<i64>, count: usize) -> Vec<i64> {
     result
 }
 
-fn main() {
-    const KEY: i64 = 811589153;
-    let arr: Vec<i64> = aoc2022::parse_line_by_line(aoc2022::get_input_file(), |s| {
-        s.parse::<i64>().expect("expected int")
-    })
-    .map(|v| v * KEY)
-    .collect();
+fn decrypt(arr: &[i64], key: i64, count: usize) -> Result<Answer, AocError> {
+    if !arr.contains(&0) {
+        return Err(AocError::unsolvable("expected 0 in the sequence"));
+    }
+
+    let arr: Vec<i64> = arr.iter().map(|v| v * key).collect();
 
-    let mixed = mixin(arr, 10);
+    let mixed = mixin(arr, count);
 
     let answer: i64 = [1000, 2000, 3000]
         .into_iter()
@@ -68,6 +74,25 @@ fn main() {
         .map(|p| mixed[p])
         .sum();
 
-    // println!("{:?}", &mixed[..100]);
-    println!("{answer}")
+    Ok(answer.into())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        let arr = crate::try_parse_line_by_line(reader, |s| s.parse::<i64>().map_err(AocError::from));
+        Ok(arr.collect::<Result<_, _>>()?)
+    }
+
+    fn part1(arr: &Self::Input) -> Result<Answer, AocError> {
+        decrypt(arr, 1, 1)
+    }
+
+    fn part2(arr: &Self::Input) -> Result<Answer, AocError> {
+        const KEY: i64 = 811589153;
+        decrypt(arr, KEY, 10)
+    }
 }
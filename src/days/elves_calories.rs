@@ -0,0 +1,42 @@
+use std::io::BufRead;
+
+use crate::{AocError, Answer};
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<i64>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, AocError> {
+        // calories of each item, empty line separates elves
+        let items = crate::try_parse_line_by_line(reader, |s| -> Result<_, AocError> {
+            Ok((!s.is_empty()).then(|| s.parse::<i64>()).transpose()?)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let mut items = items.into_iter();
+        let extract_one_elf = move || {
+            let items = items.by_ref();
+            let cal_sum : i64 = items.map_while(|cal_value| cal_value).sum();
+            (cal_sum != 0).then_some(cal_sum)
+        };
+
+        Ok(std::iter::from_fn(extract_one_elf).collect())
+    }
+
+    fn part1(elfs: &Self::Input) -> Result<Answer, AocError> {
+        let max = elfs
+            .iter()
+            .copied()
+            .max()
+            .ok_or_else(|| AocError::unsolvable("expected at least one elf"))?;
+        Ok(max.into())
+    }
+
+    fn part2(elfs: &Self::Input) -> Result<Answer, AocError> {
+        let mut elfs = elfs.clone();
+        elfs.sort();
+        let top3_sum : i64 = elfs.into_iter().rev().take(3).sum();
+        Ok(top3_sum.into())
+    }
+}
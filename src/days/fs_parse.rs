@@ -3,6 +3,8 @@ use std::{
     io::BufRead,
 };
 
+use crate::{AocError, Answer};
+
 type DirInode = usize;
 
 #[derive(Debug)]
@@ -20,7 +22,7 @@ struct Directory {
 
 
 #[derive(Debug)]
-struct FS {
+pub struct FS {
     directories: Vec<Directory>,
 }
 
@@ -74,12 +76,11 @@ impl FS {
     }
 
     fn directory_sizes(&self) -> Vec<usize> {
-        let mut sizes = Vec::with_capacity(self.directories.len());
-        sizes.resize(self.directories.len(), 0);
+        let mut sizes = vec![0; self.directories.len()];
         self.directories.iter().enumerate().rev().for_each(|(dirno, dir)| {
-            let dir_size: usize = dir.items.values().map(|item| match item {
-                &Node::File { size } => size,
-                &Node::Directory(inode) => sizes[inode]
+            let dir_size: usize = dir.items.values().map(|item| match *item {
+                Node::File { size } => size,
+                Node::Directory(inode) => sizes[inode]
             }).sum();
             sizes[dirno] = dir_size;
         });
@@ -94,63 +95,77 @@ enum Command {
     TouchDir { dname: String },
 }
 
-fn parse_command(input: &str) -> Command {
+fn parse_command(input: &str) -> Result<Command, AocError> {
     let mut tokens = input.split_whitespace();
-    let first = tokens.next().expect("at least two token expected");
-    let second = tokens.next().expect("at least two tokens expected");
-    match (first, second) {
+    let mut next = |msg: &str| tokens.next().ok_or_else(|| AocError::parse(msg));
+    let first = next("at least two tokens expected")?;
+    let second = next("at least two tokens expected")?;
+    let cmd = match (first, second) {
         ("$", "ls") => Command::Ls,
         ("$", "cd") => Command::Cd {
-            dname: tokens.next().expect("dirname expected").to_string(),
+            dname: next("dirname expected")?.to_string(),
         },
         ("dir", name) => Command::TouchDir {
             dname: name.to_string(),
         },
         (size, name) => Command::AddFile {
             name: name.to_string(),
-            size: size.parse().expect("should be int"),
+            size: size.parse()?,
         },
-    }
+    };
+    Ok(cmd)
 }
 
-fn main() {
-    let infile = aoc2022::get_input_file();
-    let parser = std::io::BufReader::new(infile);
-    let mut fs = FS::new();
-    let mut dirno = fs.root();
-
-    parser
-        .lines()
-        .map(Result::unwrap)
-        .filter(|s| !s.is_empty())
-        .map(|line| parse_command(&line))
-        .for_each(|cmd|{
-            match cmd {
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = FS;
+
+    fn parse(parser: impl BufRead) -> Result<Self::Input, AocError> {
+        let mut fs = FS::new();
+        let mut dirno = fs.root();
+
+        crate::try_parse_line_by_line(parser, |line| {
+            if line.is_empty() {
+                return Ok(());
+            }
+            match parse_command(line)? {
                 Command::Ls => (),
                 Command::Cd { dname } => {
-                    let newdir = fs.cd(dirno, &dname).expect("should be existing dir");
+                    let newdir = fs.cd(dirno, &dname).ok_or_else(|| {
+                        AocError::parse(format!("no directory {dname} to cd into"))
+                    })?;
                     dirno = newdir
                 }
                 Command::AddFile { name, size } => fs.add_file(dirno, name, size),
                 Command::TouchDir { dname } => fs.touch_directory(dirno, dname)
             }
-        });
-
-    println!("{fs:#?}");
-
-    let sizes = fs.directory_sizes();
-    let used = sizes[0];
-    let total = 70000000;
-    let current_empty = total - used;
-    let need_empty = 30000000;
+            Ok::<_, AocError>(())
+        })
+        .collect::<Result<(), _>>()?;
 
+        Ok(fs)
+    }
 
+    fn part1(fs: &Self::Input) -> Result<Answer, AocError> {
+        let sizes = fs.directory_sizes();
+        let size : usize = sizes.into_iter().filter(|&size| size <= 100000).sum();
+        Ok(size.into())
+    }
 
-    // let size : usize = sizes.into_iter().filter(|&size| size <= 100000).sum();
+    fn part2(fs: &Self::Input) -> Result<Answer, AocError> {
+        let sizes = fs.directory_sizes();
+        let used = sizes[0];
+        let total: usize = 70000000;
+        let current_empty = total
+            .checked_sub(used)
+            .ok_or_else(|| AocError::unsolvable("files take more than the whole disk"))?;
+        let need_empty = 30000000;
 
-    let size = sizes.into_iter().filter_map(|size| {
-        (current_empty + size >= need_empty).then_some(size)
-    }).min();
+        let size = sizes.into_iter().filter(|&size| {
+            current_empty + size >= need_empty
+        }).min().ok_or_else(|| AocError::unsolvable("expected directory to delete"))?;
 
-    println!("{size:?}")
+        Ok(size.into())
+    }
 }
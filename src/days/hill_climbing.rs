@@ -0,0 +1,78 @@
+use crate::{AocError, Answer};
+
+fn height(c: u8) -> i64 {
+    match c as char {
+        'S' => 'a' as _,
+        'E' => 'z' as _,
+        _ => c as _,
+    }
+}
+
+fn can_step(from: u8, to: u8) -> bool {
+    let from = height(from);
+    let to = height(to);
+    from + 1 >= to
+}
+
+fn shortest_path(
+    grid: &crate::Array2D<u8>,
+    is_start: impl Fn(char) -> bool,
+) -> Result<usize, AocError> {
+    let start = grid
+        .iter_indexed()
+        .filter_map(|(idx, &val)| is_start(val as char).then_some(idx));
+
+    let end = grid
+        .iter_indexed()
+        .find_map(|(idx, &val)| (val as char == 'E').then_some(idx))
+        .ok_or_else(|| AocError::unsolvable("end should be there"))?;
+
+    let mut visited = crate::BitGrid::new(grid.shape());
+    let mut queue = std::collections::VecDeque::new();
+
+    queue.extend(
+        start
+            .into_iter()
+            .inspect(|&pos| {
+                visited.insert(pos);
+            })
+            .map(|pos| (0, pos)),
+    );
+
+    while let Some((d, pos)) = queue.pop_front() {
+        if pos == end {
+            return Ok(d);
+        }
+        let cur = grid[pos];
+        let next = grid
+            .neighbors4(pos)
+            .filter(|&next| {
+                let next = grid[next];
+                can_step(cur, next)
+            })
+            .filter_map(|pos| visited.insert(pos).then_some((d + 1, pos)));
+        queue.extend(next)
+    }
+    Err(AocError::unsolvable("end is unreachable"))
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = crate::Array2D<u8>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        crate::Array2D::read_grid(reader, |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c as u8),
+            _ => Err(AocError::parse(format!("unexpected height {c:?}"))),
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer, AocError> {
+        Ok(shortest_path(grid, |c| c == 'S')?.into())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer, AocError> {
+        Ok(shortest_path(grid, |c| matches!(c, 'a' | 'S'))?.into())
+    }
+}
@@ -0,0 +1,120 @@
+pub mod beacons;
+pub mod camp_cleanup;
+pub mod cathode_ray;
+pub mod day20;
+pub mod elves_calories;
+pub mod fs_parse;
+pub mod hill_climbing;
+pub mod monkey;
+pub mod monkey_math;
+pub mod packets_cmp;
+pub mod robots;
+pub mod rocks_tetris;
+pub mod rope_bridge;
+pub mod rsp;
+pub mod ruksaks;
+pub mod sand_tracer;
+pub mod supply_stacks;
+pub mod tree_map;
+pub mod tuning_trouble;
+pub mod vaves_day16;
+pub mod voxel_boulders;
+
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+use crate::{AocError, Answer, Solution};
+
+pub struct PartRun {
+    pub part: u32,
+    pub answer: Result<Answer, AocError>,
+    pub elapsed: Duration,
+    // values the solver reported along the way, see `crate::diagnostics`
+    pub diagnostics: Vec<(&'static str, Answer)>,
+}
+
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+fn solve<S: Solution>(input: &mut dyn BufRead, parts: &[u32]) -> Result<Run, AocError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            crate::diagnostics::take();
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input),
+                2 => S::part2(&input),
+                _ => panic!("unexpected part {part}"),
+            };
+            let elapsed = start.elapsed();
+            PartRun {
+                part,
+                answer,
+                elapsed,
+                diagnostics: crate::diagnostics::take(),
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        parse_elapsed,
+        parts,
+    })
+}
+
+pub struct Day {
+    pub day: u32,
+    pub name: &'static str,
+    solve: fn(&mut dyn BufRead, &[u32]) -> Result<Run, AocError>,
+}
+
+impl Day {
+    // parses the input once and runs the requested parts (1 and/or 2) on it;
+    // a part failing doesn't prevent the other one from running
+    pub fn solve(&self, input: &mut dyn BufRead, parts: &[u32]) -> Result<Run, AocError> {
+        (self.solve)(input, parts)
+    }
+}
+
+const fn day<S: Solution>(day: u32, name: &'static str) -> Day {
+    Day {
+        day,
+        name,
+        solve: solve::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day::<elves_calories::Solver>(1, "elves_calories"),
+    day::<rsp::Solver>(2, "rsp"),
+    day::<ruksaks::Solver>(3, "ruksaks"),
+    day::<camp_cleanup::Solver>(4, "camp_cleanup"),
+    day::<supply_stacks::Solver>(5, "supply_stacks"),
+    day::<tuning_trouble::Solver>(6, "tuning_trouble"),
+    day::<fs_parse::Solver>(7, "fs_parse"),
+    day::<tree_map::Solver>(8, "tree_map"),
+    day::<rope_bridge::Solver>(9, "rope_bridge"),
+    day::<cathode_ray::Solver>(10, "cathode_ray"),
+    day::<monkey::Solver>(11, "monkey"),
+    day::<hill_climbing::Solver>(12, "hill_climbing"),
+    day::<packets_cmp::Solver>(13, "packets_cmp"),
+    day::<sand_tracer::Solver>(14, "sand_tracer"),
+    day::<beacons::Solver>(15, "beacons"),
+    day::<vaves_day16::Solver>(16, "vaves_day16"),
+    day::<rocks_tetris::Solver>(17, "rocks_tetris"),
+    day::<voxel_boulders::Solver>(18, "voxel_boulders"),
+    day::<robots::Solver>(19, "robots"),
+    day::<day20::Solver>(20, "day20"),
+    day::<monkey_math::Solver>(21, "monkey_math"),
+];
+
+pub fn find(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
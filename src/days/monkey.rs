@@ -0,0 +1,198 @@
+use std::io::BufRead;
+
+use crate::{AocError, Answer};
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Add(i64),
+    Mult(i64),
+    Sqr
+}
+
+impl Operation {
+    fn apply(self, old: i64) -> i64 {
+        match self {
+            Self::Add(x) => old + x,
+            Self::Mult(x) => old * x,
+            Self::Sqr => old * old,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tester {
+    value: i64,
+    on_true: usize,
+    on_false: usize,
+}
+
+impl Tester {
+    fn test(&self, val: i64) -> usize {
+        if val % self.value == 0 {
+            self.on_true
+        } else {
+            self.on_false
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Releaf {
+    DivideBy3,
+    Modulo(i64),
+}
+
+impl Releaf {
+    fn apply(self, val: i64) -> i64 {
+        match self {
+            Self::DivideBy3 => val / 3,
+            Self::Modulo(m) => val % m,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: std::collections::VecDeque<i64>,
+    op: Operation,
+    tester: Tester,
+    inspections: usize,
+}
+
+impl Monkey {
+    fn inspect_next(&mut self, releaf : Releaf) -> Option<(usize, i64)> {
+        let item = self.items.pop_front()?;
+        let item = self.op.apply(item);
+        self.inspections += 1;
+        let item = releaf.apply(item);
+        let next = self.tester.test(item);
+        Some((next, item))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MonkeyIsland {
+    monkeys: Vec<Monkey>,
+}
+
+impl MonkeyIsland {
+    fn common_modulo(&self) -> i64 {
+        self.monkeys.iter().map(|m| m.tester.value).product()
+    }
+
+    fn round(&mut self, releaf: Releaf) -> &mut Self {
+        for idx in 0..self.monkeys.len() {
+            while let Some((idx, val)) = self.monkeys[idx].inspect_next(releaf) {
+                self.monkeys[idx].items.push_back(val)
+            }
+        }
+        self
+    }
+
+    fn business(&self) -> usize {
+        let mut busines: Vec<_> = self.monkeys.iter().map(|m| m.inspections).collect();
+        busines.sort_by(|x, y| x.cmp(y).reverse());
+        busines.into_iter().take(2).product()
+    }
+}
+
+fn last_number(line: &str) -> Result<i64, AocError> {
+    let token = line
+        .split_whitespace()
+        .next_back()
+        .ok_or_else(|| AocError::parse("expected number at the end of line"))?;
+    Ok(token.parse()?)
+}
+
+fn parse_monkey(lines: &[String]) -> Result<Monkey, AocError> {
+    let mut lines = lines.iter();
+    let mut next_line = || {
+        lines
+            .next()
+            .ok_or_else(|| AocError::parse("incomplete monkey description"))
+    };
+    let _header = next_line()?;
+    let (_, items) = next_line()?
+        .split_once(':')
+        .ok_or_else(|| AocError::parse("expected starting items"))?;
+    let items = items
+        .split(',')
+        .map(str::trim)
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+
+    let mut operation = next_line()?.split_whitespace();
+    let arg = operation.next_back();
+    let op = operation.next_back();
+    let op = match (op, arg) {
+        (Some("*"), Some("old")) => Operation::Sqr,
+        (Some("*"), Some(x)) => Operation::Mult(x.parse()?),
+        (Some("+"), Some(x)) => Operation::Add(x.parse()?),
+        (s, arg) => return Err(AocError::parse(format!("Unknown op: {s:?} {arg:?}"))),
+    };
+
+    let test_value = last_number(next_line()?)?;
+    if test_value <= 0 {
+        return Err(AocError::parse("expected positive divisor"));
+    }
+    let on_true = last_number(next_line()?)? as usize;
+    let on_false = last_number(next_line()?)? as usize;
+
+    Ok(Monkey {
+        items,
+        inspections : 0,
+        tester : Tester { value: test_value, on_true, on_false },
+        op
+    })
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = MonkeyIsland;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, AocError> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        let monkeys = lines
+            .split(|line| line.is_empty())
+            .filter(|block| !block.is_empty())
+            .enumerate()
+            .map(|(idx, block)| {
+                parse_monkey(block).map_err(|err| match err {
+                    AocError::Parse(msg) => AocError::parse(format!("monkey {idx}: {msg}")),
+                    err => err,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut targets = monkeys
+            .iter()
+            .flat_map(|m| [m.tester.on_true, m.tester.on_false]);
+        if let Some(target) = targets.find(|&target| target >= monkeys.len()) {
+            return Err(AocError::parse(format!("no monkey {target} to throw to")));
+        }
+
+        Ok(MonkeyIsland { monkeys })
+    }
+
+    fn part1(island: &Self::Input) -> Result<Answer, AocError> {
+        let mut island = island.clone();
+
+        for _ in 0..20 {
+            island.round(Releaf::DivideBy3);
+        }
+
+        Ok(island.business().into())
+    }
+
+    fn part2(island: &Self::Input) -> Result<Answer, AocError> {
+        let mut island = island.clone();
+        let releaf = Releaf::Modulo(island.common_modulo());
+
+        for _ in 0..10000 {
+            island.round(releaf);
+        }
+
+        Ok(island.business().into())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{AocError, Answer};
+
+
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div
+}
+
+type MonkeyName = String;
+type MonkeyRef<'a> = &'a str;
+
+#[derive(Clone)]
+pub enum Monkey {
+    Val(i64),
+    Op {
+        op: Operation,
+        left: MonkeyName,
+        right: MonkeyName,
+    }
+}
+
+impl Operation {
+    // `None` when dividing by zero
+    fn eval(self, lhs: i64, rhs : i64) -> Option<i64> {
+        match self {
+            Self::Add => Some(lhs + rhs),
+            Self::Div => lhs.checked_div(rhs),
+            Self::Mul => Some(lhs * rhs),
+            Self::Sub => Some(lhs - rhs)
+        }
+    }
+
+    fn parse(token: &str) -> Result<Self, AocError> {
+        match token {
+            "+" => Ok(Self::Add),
+            "-" => Ok(Self::Sub),
+            "/" => Ok(Self::Div),
+            "*" => Ok(Self::Mul),
+            _ => Err(AocError::parse(format!("unexpected op {token}")))
+        }
+    }
+
+    // `None` when no integer gives `res`
+    fn solve_right(self, lhs: i64, res: i64) -> Option<i64> {
+        match self {
+            Self::Add => Some(res - lhs),
+            Self::Div => exact_div(lhs, res).filter(|&rhs| rhs != 0),
+            Self::Mul => exact_div(res, lhs),
+            Self::Sub => Some(lhs - res),
+        }
+    }
+
+    // `None` when no integer gives `res`
+    fn solve_left(self, rhs: i64, res: i64) -> Option<i64> {
+        match self {
+            Self::Add => Some(res - rhs),
+            Self::Div => (rhs != 0).then(|| rhs * res),
+            Self::Mul => exact_div(res, rhs),
+            Self::Sub => Some(rhs + res),
+        }
+    }
+}
+
+fn exact_div(lhs: i64, rhs: i64) -> Option<i64> {
+    (lhs.checked_rem(rhs)? == 0).then(|| lhs / rhs)
+}
+
+impl Monkey {
+    fn parse(line: &str) -> Result<Self, AocError> {
+        if line.chars().all(|c| c.is_ascii_digit()) {
+            Ok(Self::Val(line.parse()?))
+        } else {
+            let mut tokens = line.split_whitespace();
+            let mut next = || tokens.next().ok_or_else(|| AocError::parse("expected `left op right`"));
+            let left = next()?.to_string();
+            let op = Operation::parse(next()?)?;
+            let right = next()?.to_string();
+            Ok(Self::Op { op, left, right })
+        }
+    }
+}
+
+pub type Monkeys = std::collections::HashMap<MonkeyName, Monkey>;
+type EvaluatedMonkeys = std::collections::HashMap<MonkeyName, i64>;
+
+// `None` when the value depends on an unknown monkey
+fn evaluate(monkeys: &Monkeys, root: MonkeyRef<'_>, precomputed: &mut EvaluatedMonkeys) -> Result<Option<i64>, AocError> {
+    if let Some(&val) = precomputed.get(root) {
+        return Ok(Some(val));
+    }
+
+    let Some(monkey) = monkeys.get(root) else {
+        return Ok(None);
+    };
+
+    let value = match monkey {
+        &Monkey::Val(v) => v,
+        Monkey::Op { op, left, right } => {
+            let left = evaluate(monkeys, left, precomputed)?;
+            let right = evaluate(monkeys, right, precomputed)?;
+            let (Some(left), Some(right)) = (left, right) else {
+                return Ok(None);
+            };
+            op.eval(left, right)
+                .ok_or_else(|| AocError::unsolvable(format!("{root} divides by zero")))?
+        }
+    };
+
+    precomputed.insert(root.to_string(), value);
+
+    Ok(Some(value))
+}
+
+
+
+fn evaluate2(monkeys: &Monkeys, root: MonkeyRef<'_>, hum: MonkeyRef<'_>, precomputed: &mut EvaluatedMonkeys) -> Result<i64, AocError> {
+    let Some(Monkey::Op { left, right, .. }) = monkeys.get(root) else {
+        return Err(AocError::unsolvable("root should be Op"));
+    };
+
+    let left_v = evaluate(monkeys, left, precomputed)?;
+    let right_v = evaluate(monkeys, right, precomputed)?;
+
+    match (left_v, right_v) {
+        (Some(lhs), None) => {
+            solve(monkeys, lhs, right, hum, precomputed)
+        },
+        (None, Some(rhs)) => {
+            solve(monkeys, rhs, left, hum, precomputed)
+        },
+        _ => Err(AocError::unsolvable("expected value"))
+    }
+}
+
+fn solve(monkeys: &Monkeys, value: i64, cur: MonkeyRef<'_>, hum: MonkeyRef<'_>, precomputed: &mut EvaluatedMonkeys) -> Result<i64, AocError> {
+    if cur == hum {
+        return Ok(value);
+    }
+
+    let Some(Monkey::Op { op, left, right }) = monkeys.get(cur)
+    else {
+        return Err(AocError::unsolvable("expected value"));
+    };
+
+    let left_v = evaluate(monkeys, left, precomputed)?;
+    let right_v = evaluate(monkeys, right, precomputed)?;
+
+    let no_integer = || AocError::unsolvable(format!("no integer {hum} gives {value} at {cur}"));
+    match (left_v, right_v) {
+        (Some(lhs), None) => {
+            let rhs = op.solve_right(lhs, value).ok_or_else(no_integer)?;
+            solve(monkeys, rhs, right, hum, precomputed)
+        },
+        (None, Some(rhs)) => {
+            let lhs = op.solve_left(rhs, value).ok_or_else(no_integer)?;
+            solve(monkeys, lhs, left, hum, precomputed)
+        },
+        // either both sides depend on humn or none of them
+        _ => Err(AocError::unsolvable("expected value"))
+    }
+}
+
+
+fn parse_monkey(line: &str) -> Result<(MonkeyName, Monkey), AocError> {
+    let (name, desc) = line
+        .split_once(':')
+        .ok_or_else(|| AocError::parse("expected monkey_name: desc"))?;
+    let desc = desc.trim();
+    
+    let name = name.to_string();
+    Ok((name, Monkey::parse(desc)?))
+}
+
+const ROOT : MonkeyRef = "root";
+const HUMN : MonkeyRef = "humn";
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Monkeys;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        Ok(crate::try_parse_line_by_line(reader, parse_monkey).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Answer, AocError> {
+        let mut cache = EvaluatedMonkeys::default();
+
+        let val = evaluate(monkeys, ROOT, &mut cache)?
+            .ok_or_else(|| AocError::unsolvable("expected root to be evaluated"))?;
+        Ok(val.into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Answer, AocError> {
+        let mut monkeys = monkeys.clone();
+        let mut cache = EvaluatedMonkeys::default();
+
+        monkeys.remove(HUMN);
+        let val = evaluate2(&monkeys, ROOT, HUMN, &mut cache)?;
+        Ok(val.into())
+    }
+}
@@ -0,0 +1,110 @@
+use std::iter::Peekable;
+
+use crate::{iter_ext::IteratorExt, AocError, Answer};
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Val(i64),
+    Sub(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+impl Eq for Packet {}
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Sub(vself), Self::Sub(vother)) => vself.cmp(vother),
+            (Self::Val(xself), Self::Val(yother)) => xself.cmp(yother),
+            (Self::Sub(vself), Self::Val(xother)) => vself[..].cmp(&[Self::Val(*xother)]),
+            (Self::Val(xself), Self::Sub(vother)) => [Self::Val(*xself)][..].cmp(vother),
+        }
+    }
+}
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_packet_item(
+    chars: &mut Peekable<impl Iterator<Item = char>>,
+) -> Result<Option<Packet>, AocError> {
+    if chars.next_if_eq(&'[').is_some() {
+        let mut items = Vec::new();
+        while let Some(item) = parse_packet_item(chars)? {
+            items.push(item);
+            let _ = chars.next_if_eq(&',');
+        }
+        chars
+            .next_if_eq(&']')
+            .ok_or_else(|| AocError::parse("expected list termination"))?;
+        return Ok(Some(Packet::Sub(items)));
+    }
+    let val = std::iter::from_fn(|| {
+        chars
+            .next_if(|c| c.is_ascii_digit())
+            .and_then(|d| d.to_digit(10).map(|d| d as i64))
+    })
+    .reduce(|acc, digit| acc * 10 + digit)
+    .map(Packet::Val);
+    Ok(val)
+}
+
+fn parse_packet(packet: &str) -> Result<Packet, AocError> {
+    let mut chars = packet.chars().peekable();
+    let packet = parse_packet_item(&mut chars)?.ok_or_else(|| AocError::parse("expected packet"))?;
+    if let Some(c) = chars.next() {
+        return Err(AocError::parse(format!("unexpected {c:?} after the packet")));
+    }
+    Ok(packet)
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<Packet>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        let packets = crate::try_parse_line_by_line(reader, |line| {
+            (!line.is_empty()).then(|| parse_packet(line)).transpose()
+        });
+        Ok(packets.filter_map(Result::transpose).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(packets: &Self::Input) -> Result<Answer, AocError> {
+        let idx_sum: i64 = packets
+            .iter()
+            .batching(|iter| {
+                let first = iter.next()?;
+                let second = iter.next()?;
+                Some((first, second))
+            })
+            .zip(1..)
+            .filter_map(|((left, right), idx)| (left < right).then_some(idx))
+            .sum();
+
+        Ok(idx_sum.into())
+    }
+
+    fn part2(packets: &Self::Input) -> Result<Answer, AocError> {
+        let first_term = parse_packet("[[2]]")?;
+        let last_term = parse_packet("[[6]]")?;
+        let mut packets: Vec<_> = packets
+            .iter()
+            .cloned()
+            .chain([first_term.clone(), last_term.clone()])
+            .collect();
+        packets.sort();
+
+        let idx_prod: i64 = packets
+            .iter()
+            .zip(1..)
+            .filter_map(|(p, idx)| (p == &first_term || p == &last_term).then_some(idx))
+            .product();
+        Ok(idx_prod.into())
+    }
+}
@@ -1,38 +1,51 @@
-fn main() {
-    // // p1
-    let score: usize = aoc2022::parse_line_by_line(aoc2022::get_input_file(), parse_blueprint)
-        .map(|bprint| bprint.estimate(State::start_p1()))
-        .zip(1..)
-        .map(|(x, y)| x * y)
-        .sum();
-    println!("{score}")
-    // p2
-    // let score: usize = aoc2022::parse_line_by_line(aoc2022::get_input_file(), parse_blueprint)
-    //     .take(3)
-    //     .map(|bprint| bprint.estimate(State::start_p2()))
-    //     .inspect(|d| {
-    //         dbg!(d);
-    //     })
-    //     .product();
-
-    // println!("{score}")
+use crate::{AocError, Answer};
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<Blueprint>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        Ok(crate::try_parse_line_by_line(reader, parse_blueprint).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(blueprints: &Self::Input) -> Result<Answer, AocError> {
+        let score: usize = blueprints
+            .iter()
+            .map(|bprint| bprint.estimate(State::start_p1()))
+            .zip(1..)
+            .map(|(x, y)| x * y)
+            .sum();
+        Ok(score.into())
+    }
+
+    fn part2(blueprints: &Self::Input) -> Result<Answer, AocError> {
+        let score: usize = blueprints
+            .iter()
+            .take(3)
+            .map(|bprint| bprint.estimate(State::start_p2()))
+            .product();
+        Ok(score.into())
+    }
 }
 
-fn parse_blueprint(line: &str) -> Blueprint {
-    let (_, bp) = line.split_once(':').expect("wrong format");
+type ParseResult<T> = Result<T, AocError>;
+
+fn parse_blueprint(line: &str) -> ParseResult<Blueprint> {
+    let (_, bp) = line.split_once(':').ok_or_else(|| AocError::parse("wrong format"))?;
     let bp = bp.trim();
     let mut robots = bp.split('.').map(str::trim);
-    let ore = robots.next().expect("wrong format");
-    let clay = robots.next().expect("wrong format");
-    let obsidian = robots.next().expect("wrong format");
-    let geod = robots.next().expect("wrong format");
-
-    Blueprint {
-        obsidian: ObsidianRobot::parse_line(obsidian),
-        geode: GeodeRobot::parse_line(geod),
-        clay: ClayRobot::parse_line(clay),
-        ore: OreRobot::parse_line(ore),
-    }
+    let ore = robots.next().ok_or_else(|| AocError::parse("wrong format"))?;
+    let clay = robots.next().ok_or_else(|| AocError::parse("wrong format"))?;
+    let obsidian = robots.next().ok_or_else(|| AocError::parse("wrong format"))?;
+    let geod = robots.next().ok_or_else(|| AocError::parse("wrong format"))?;
+
+    Ok(Blueprint {
+        obsidian: ObsidianRobot::parse_line(obsidian)?,
+        geode: GeodeRobot::parse_line(geod)?,
+        clay: ClayRobot::parse_line(clay)?,
+        ore: OreRobot::parse_line(ore)?,
+    })
 }
 
 struct OreRobot {
@@ -54,48 +67,48 @@ struct GeodeRobot {
 }
 
 impl OreRobot {
-    fn parse_line(line: &str) -> Self {
-        let ore = line.split_whitespace().nth_back(1).expect("wrong format");
-        Self {
-            need_ore: ore.parse().expect("expected int"),
-        }
+    fn parse_line(line: &str) -> ParseResult<Self> {
+        let ore = line.split_whitespace().nth_back(1).ok_or_else(|| AocError::parse("wrong format"))?;
+        Ok(Self {
+            need_ore: ore.parse()?,
+        })
     }
 }
 
 impl ClayRobot {
-    fn parse_line(line: &str) -> Self {
-        let ore = line.split_whitespace().nth_back(1).expect("wrong format");
-        Self {
-            need_ore: ore.parse().expect("expected int"),
-        }
+    fn parse_line(line: &str) -> ParseResult<Self> {
+        let ore = line.split_whitespace().nth_back(1).ok_or_else(|| AocError::parse("wrong format"))?;
+        Ok(Self {
+            need_ore: ore.parse()?,
+        })
     }
 }
 
 impl ObsidianRobot {
-    fn parse_line(line: &str) -> Self {
+    fn parse_line(line: &str) -> ParseResult<Self> {
         let mut tokens = line.split_whitespace();
-        let clay = tokens.nth_back(1).expect("wrong format");
-        let ore = tokens.nth_back(2).expect("wrong format");
-        Self {
-            need_ore: ore.parse().expect("expected int"),
-            need_clay: clay.parse().expect("expected int"),
-        }
+        let clay = tokens.nth_back(1).ok_or_else(|| AocError::parse("wrong format"))?;
+        let ore = tokens.nth_back(2).ok_or_else(|| AocError::parse("wrong format"))?;
+        Ok(Self {
+            need_ore: ore.parse()?,
+            need_clay: clay.parse()?,
+        })
     }
 }
 
 impl GeodeRobot {
-    fn parse_line(line: &str) -> Self {
+    fn parse_line(line: &str) -> ParseResult<Self> {
         let mut tokens = line.split_whitespace();
-        let obsidian = tokens.nth_back(1).expect("wrong format");
-        let ore = tokens.nth_back(2).expect("wrong format");
-        Self {
-            need_ore: ore.parse().expect("expected int"),
-            need_obsidian: obsidian.parse().expect("expected int"),
-        }
+        let obsidian = tokens.nth_back(1).ok_or_else(|| AocError::parse("wrong format"))?;
+        let ore = tokens.nth_back(2).ok_or_else(|| AocError::parse("wrong format"))?;
+        Ok(Self {
+            need_ore: ore.parse()?,
+            need_obsidian: obsidian.parse()?,
+        })
     }
 }
 
-struct Blueprint {
+pub struct Blueprint {
     ore: OreRobot,
     clay: ClayRobot,
     obsidian: ObsidianRobot,
@@ -130,7 +143,7 @@ trait StateUpdater {
 
 impl StateUpdater for OreRobot {
     fn try_update(&self, state: State) -> Option<State> {
-        (state.ore >= self.need_ore).then_some(State {
+        (state.ore >= self.need_ore).then(|| State {
             ore: state.ore - self.need_ore,
             ore_robots: state.ore_robots + 1,
             ..state
@@ -150,7 +163,7 @@ impl StateUpdater for OreRobot {
 
 impl StateUpdater for ClayRobot {
     fn try_update(&self, state: State) -> Option<State> {
-        (state.ore >= self.need_ore).then_some(State {
+        (state.ore >= self.need_ore).then(|| State {
             ore: state.ore - self.need_ore,
             clay_robots: state.clay_robots + 1,
             ..state
@@ -164,7 +177,7 @@ impl StateUpdater for ClayRobot {
 
 impl StateUpdater for ObsidianRobot {
     fn try_update(&self, state: State) -> Option<State> {
-        (state.ore >= self.need_ore && state.clay >= self.need_clay).then_some(State {
+        (state.ore >= self.need_ore && state.clay >= self.need_clay).then(|| State {
             ore: state.ore - self.need_ore,
             clay: state.clay - self.need_clay,
             obsidian_robots: state.obsidian_robots + 1,
@@ -179,7 +192,7 @@ impl StateUpdater for ObsidianRobot {
 
 impl StateUpdater for GeodeRobot {
     fn try_update(&self, state: State) -> Option<State> {
-        (state.ore >= self.need_ore && state.obsidian >= self.need_obsidian).then_some(State {
+        (state.ore >= self.need_ore && state.obsidian >= self.need_obsidian).then(|| State {
             ore: state.ore - self.need_ore,
             obsidian: state.obsidian - self.need_obsidian,
             geode_robots: state.geode_robots + 1,
@@ -187,7 +200,7 @@ impl StateUpdater for GeodeRobot {
         })
     }
 
-    fn enough(&self, state: State, bp: &Blueprint) -> bool {
+    fn enough(&self, _state: State, _bp: &Blueprint) -> bool {
         false
     }
 }
@@ -199,7 +212,7 @@ impl StateUpdater for Noop {
         Some(state)
     }
 
-    fn enough(&self, state: State, bp: &Blueprint) -> bool {
+    fn enough(&self, _state: State, _bp: &Blueprint) -> bool {
         false
     }
 }
@@ -260,7 +273,7 @@ impl State {
         let updaters: [&dyn StateUpdater; 5] = [&bp.ore, &bp.clay, &bp.obsidian, &bp.geode, &Noop];
         updaters
             .into_iter()
-            .filter(move |upd| !upd.enough(self, &bp))
+            .filter(move |upd| !upd.enough(self, bp))
             .flat_map(move |upd| upd.try_update(self))
             .count()
             == 1
@@ -279,25 +292,28 @@ impl State {
         bp: &'b Blueprint,
     ) -> impl Iterator<Item = (Self, usize)> + 'b {
         let produced = self.produce_new_resources();
-        let mut gain = produced.geode;
+        // geodes cracked while waiting for resources to build something
+        let mut gain = 0;
         let mut state = *self;
         while (!state.can_build_something(bp) || state.only_noop_can_be(bp)) && state.time > 0 {
             state = state.tick();
             state = state.apply(produced);
-            gain = gain + produced.geode;
+            gain += produced.geode;
         }
 
         if state.time == 0 {
-            aoc2022::either::Either::Left(std::iter::once((state, gain)))
+            crate::either::Either::Left(std::iter::once((state, gain)))
         } else {
+            // plus the minute spent on building
+            let gain = gain + produced.geode;
             let with_build = if let Some(next) = bp.geode.try_update(state) {
-                aoc2022::either::Either::Left(std::iter::once((next.tick().apply(produced), gain)))
+                crate::either::Either::Left(std::iter::once((next.tick().apply(produced), gain)))
             } else {
                 let updaters: [&dyn StateUpdater; 4] = [&bp.obsidian, &bp.clay, &bp.ore, &Noop];
-                aoc2022::either::Either::Right(
+                crate::either::Either::Right(
                     updaters
                         .into_iter()
-                        .filter(move |upd| !upd.enough(state, &bp))
+                        .filter(move |upd| !upd.enough(state, bp))
                         .flat_map(move |upd| upd.try_update(state))
                         .map(move |state| state.apply(produced))
                         .map(State::tick)
@@ -305,12 +321,15 @@ impl State {
                 )
             };
 
-            aoc2022::either::Either::Right(with_build)
+            crate::either::Either::Right(with_build)
         }
     }
 
     fn approx_max_geode_gain(&self) -> usize {
         let ticks = self.time;
+        if ticks == 0 {
+            return 0;
+        }
         let max = self.geode_robots + ticks - 1;
         (self.geode_robots + max) * ticks / 2
     }
@@ -365,3 +384,16 @@ impl Blueprint {
         max_val
     }
 }
+
+#[test]
+fn test_idle_robots() {
+    // nothing is affordable in time, so the robots only collect
+    let bp = parse_blueprint(
+        "Blueprint 1: Each ore robot costs 9 ore. Each clay robot costs 9 ore. \
+         Each obsidian robot costs 9 ore and 9 clay. Each geode robot costs 9 ore and 9 obsidian.",
+    )
+    .unwrap();
+    let state = State { time: 3, ..State::start_p1() };
+    assert_eq!(bp.estimate(state), 0);
+    assert_eq!(bp.estimate(State { geode_robots: 1, ..state }), 3);
+}
@@ -1,6 +1,8 @@
-use std::io::Read;
+use std::io::BufRead;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use crate::{AocError, Answer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Tile {
     Empty,
     Movable,
@@ -67,11 +69,11 @@ fn contains_movable(line: &TableLine) -> bool {
 
 #[derive(Default)]
 struct Table {
-    floor_level: usize,
     table: std::collections::VecDeque<TableLine>,
     block_first_line: Option<usize>,
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, Copy)]
 enum Block {
     Vertical,
@@ -210,10 +212,6 @@ fn freeze_line(line: &mut TableLine) {
         .for_each(|t| *t = Tile::Fill);
 }
 
-fn is_full(line: &TableLine) -> bool {
-    line.iter().all(|&t| t == Tile::Fill)
-}
-
 fn is_empty(line: &TableLine) -> bool {
     line.iter().all(|&t| t == Tile::Empty)
 }
@@ -233,7 +231,7 @@ fn move_from_to(from: &mut TableLine, to: &mut TableLine) {
 impl Table {
     fn spawn_new(&mut self, block: Block) -> &mut Self {
         self.table
-            .extend(std::iter::repeat([Tile::Empty; LINE_SIZE]).take(3));
+            .extend(std::iter::repeat_n([Tile::Empty; LINE_SIZE], 3));
         block.spawn_lines(self);
         self
     }
@@ -287,8 +285,6 @@ impl Table {
         if !can_move {
             self.table.iter_mut().skip(*block_first_line).take_while(|line| contains_movable(line)).for_each(freeze_line);
             self.block_first_line = None;
-            // self.trim_bottom();
-            // self.test_period();
             return false;
         }
 
@@ -312,81 +308,56 @@ impl Table {
     }
 
     fn height(&self) -> usize {
-        self.floor_level + self.table.len()
-    }
-
-    fn trim_bottom(&mut self) {
-
-        let Some(trim_idx) = self.table.iter().enumerate().rev().find_map(|(idx, line)| is_full(line).then_some(idx)) else {
-            return;
-        };
-
-        self.floor_level += trim_idx + 1;
-        for _ in 0..=trim_idx {
-            self.table.pop_front();
-        }
-
-        if let Some(ref mut line) = self.block_first_line {
-            *line -= trim_idx - 1;
-        }
+        self.table.len()
     }
 
+    #[allow(dead_code)]
     fn show(&self) {
-        for line in self.table.iter().rev() {
-            line.iter().map(|t| match t {
-                Tile::Empty => '.',
-                Tile::Fill => '#',
-                Tile::Movable => '@'
-            }).chain(['\n']).for_each(|c| print!("{c}"))
-        }
+        let lines = self.table.iter().rev().flatten().copied();
+        let table = crate::Array2D::from_iter(lines, (self.table.len(), LINE_SIZE))
+            .expect("every line has LINE_SIZE tiles");
+        let tile = |tile: &Tile| match tile {
+            Tile::Empty => '.',
+            Tile::Fill => '#',
+            Tile::Movable => '@',
+        };
+        print!("{}", table.render(tile));
     }
+}
 
-    fn test_period(&self) {
-
-        if self.table.back().filter(|line| is_full(line)).is_some() {
-            println!("FULL! {}", self.table.len());
-        }
+// lines of the tower surface a fingerprint covers
+const FINGERPRINT_DEPTH: usize = 64;
 
-        let n = self.table.len();
-        if n % 2 != 0 {
-            return;
-        }
+const BLOCKS: [Block; 5] = [
+    Block::Horizontal,
+    Block::Cross,
+    Block::LBlock,
+    Block::Vertical,
+    Block::Block2x2,
+];
 
-        let first = self.table.iter().take(n/2);
-        let last = self.table.iter().skip(n/2);
-
-        if first.eq(last) {
-            println!("period = {}", n/2)
-        }
-    }
-}
-
-struct Simutalion<Blocks: Iterator<Item = Block>> {
+struct Simutalion<'a> {
     table: Table,
-    blocks: Blocks,
+    jets: &'a [Action],
+    next_jet: usize,
+    next_block: usize,
 }
 
-fn init_simitation() -> Simutalion<impl Iterator<Item = Block>> {
+fn init_simitation(jets: &[Action]) -> Simutalion<'_> {
     Simutalion {
         table: Default::default(),
-        blocks: [
-            Block::Horizontal,
-            Block::Cross,
-            Block::LBlock,
-            Block::Vertical,
-            Block::Block2x2,
-        ]
-        .into_iter()
-        .cycle(),
+        jets,
+        next_jet: 0,
+        next_block: 0,
     }
 }
 
-impl<B: Iterator<Item = Block>> Simutalion<B> {
+impl Simutalion<'_> {
     // return true if block is stopped after tick
     fn tick(&mut self, action: Action) -> bool {
         if !self.table.has_block() {
-            self.table
-                .spawn_new(self.blocks.next().expect("expected block"));
+            self.table.spawn_new(BLOCKS[self.next_block]);
+            self.next_block = (self.next_block + 1) % BLOCKS.len();
         }
 
         self.table.do_action(action);
@@ -394,79 +365,106 @@ impl<B: Iterator<Item = Block>> Simutalion<B> {
 
         !self.table.has_block()
     }
+
+    // drops the next block and returns the height of the tower once it stops
+    fn drop_block(&mut self) -> i64 {
+        loop {
+            let action = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            if self.tick(action) {
+                return self.table.height() as i64;
+            }
+        }
+    }
+
+    // everything the next blocks depend on: the block and jet coming next, and a mask per line
+    // from the top of the cells a falling block can get to by moving left, right and down, since
+    // any other cell it bumps into is filled. Exact unless those cells go deeper than
+    // `FINGERPRINT_DEPTH` lines: the deeper ones are cut off as if out of reach
+    fn fingerprint(&self) -> (usize, usize, Vec<u8>) {
+        const FULL: u8 = (1 << LINE_SIZE) - 1;
+        let mut surface = Vec::new();
+        let mut above = FULL;
+        for line in self.table.table.iter().rev().take(FINGERPRINT_DEPTH) {
+            let empty = line
+                .iter()
+                .enumerate()
+                .filter(|(_, &t)| t == Tile::Empty)
+                .fold(0, |mask, (col, _)| mask | 1 << col);
+            let mut open = above & empty;
+            loop {
+                let spread = (open | open << 1 | open >> 1) & empty;
+                if spread == open {
+                    break;
+                }
+                open = spread;
+            }
+            if open == 0 {
+                break;
+            }
+            surface.push(open);
+            above = open;
+        }
+        (self.next_block, self.next_jet, surface)
+    }
 }
 
 fn map_action(c: char) -> Action {
     match c {
         '<' => Action::Left,
         '>' => Action::Right,
-        _ => unreachable!(),
-    }
-}
-
-fn find_period(vals: &[i64]) -> Option<usize> {
-    for n in (1..=(vals.len() / 2)).rev() {
-        let first = vals.iter().rev().take(n);
-        let last = vals.iter().rev().skip(n).take(n);
-        if first.eq(last) {
-            return Some(n)
-        }
+        _ => unreachable!("pattern is validated while parsing"),
     }
-    None
 }
 
-fn main() {
-    let pattern = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+// height of the tower after `blocks_cnt` blocks
+fn tower_height(pattern: &str, blocks_cnt: usize) -> Result<i64, AocError> {
+    let jets: Vec<Action> = pattern.chars().map(map_action).collect();
+    // the block and jet pairs come around at least twice before giving up
+    let limit = 10_000 + 2 * BLOCKS.len() * jets.len();
 
-    let mut pattern = String::new();
-
-    let _ = aoc2022::get_input_file().read_to_string(&mut pattern);
-
-    let actions = pattern.trim().chars().cycle().map(map_action);
-
-    let mut simulation = init_simitation();
-    const BLOCKS_CNT: usize = 8000;
-    let mut counter = 0;
-    let mut heights = Vec::default();
-    for (idx, act) in actions.enumerate() {
-        if simulation.tick(act) {
-            counter += 1;
-            heights.push(simulation.table.height() as i64);
-            if counter == BLOCKS_CNT {
-                // simulation.table.show();
-                println!("--------------------------");
-                println!("{}", simulation.table.height());
-                break;
-            }
-        }
-        // simulation.table.show();
-        // println!("---------------------------");
-        // if idx == 10 {
-            // break;
-        // }
+    let mut simulation = init_simitation(&jets);
+    if blocks_cnt <= limit {
+        return Ok((0..blocks_cnt).map(|_| simulation.drop_block()).last().unwrap_or(0));
     }
 
-    let diff : Vec<_> = heights.windows(2).map(|w| w[1] - w[0]).collect();
-
-    println!("{diff:?}");
+    let cycle = crate::cycle::find_cycle(
+        simulation,
+        limit,
+        Simutalion::fingerprint,
+        Simutalion::drop_block,
+    )
+    .ok_or_else(|| AocError::unsolvable(format!("the tower doesn't repeat within {limit} blocks")))?;
+    Ok(cycle.extrapolate(blocks_cnt))
+}
 
-    let period = find_period(&diff).expect("expected period");
-    let deltas = &diff[(diff.len() - period).. ];
+pub struct Solver;
 
-    let sum_per_period : i64 = deltas.iter().sum();
+impl crate::Solution for Solver {
+    // jet pattern
+    type Input = String;
 
-    let cur_height = simulation.table.height();
-    let already_fall = BLOCKS_CNT;
+    fn parse(mut reader: impl BufRead) -> Result<Self::Input, AocError> {
+        let mut pattern = String::new();
 
-    let NEED = 1000000000000;
-    let extra = NEED - already_fall;
+        reader.read_to_string(&mut pattern)?;
 
-    let full_periods = (extra / period) as i64;
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err(AocError::parse("expected non-empty jet pattern"));
+        }
+        if let Some(c) = pattern.chars().find(|c| !matches!(c, '<' | '>')) {
+            return Err(AocError::parse(format!("unexpected {c:?} in jet pattern")));
+        }
 
-    let rest = extra % period;
+        Ok(pattern.to_string())
+    }
 
-    let answer : i64 = cur_height as i64 + (sum_per_period * full_periods);
-    let extra_add : i64 = deltas.iter().take(rest).sum();
+    fn part1(pattern: &Self::Input) -> Result<Answer, AocError> {
+        Ok(tower_height(pattern, 2022)?.into())
+    }
 
-    println!("{}", answer + extra_add)
+    fn part2(pattern: &Self::Input) -> Result<Answer, AocError> {
+        Ok(tower_height(pattern, 1_000_000_000_000)?.into())
+    }
 }
@@ -0,0 +1,104 @@
+use crate::geometry::Dir4;
+use crate::{AocError, Answer, SparseGrid, Vec2};
+
+fn parse_command(s: &str) -> Result<(Dir4, usize), AocError> {
+    let mut tokens = s.split_whitespace();
+    let cmd = tokens.next().ok_or_else(|| AocError::parse("expected command"))?;
+    let cnt = tokens.next().ok_or_else(|| AocError::parse("expected integer"))?;
+
+    let cmd = 
+    match cmd {
+        "R" => Dir4::Right,
+        "D" => Dir4::Down,
+        "L" => Dir4::Left,
+        "U" => Dir4::Up,
+        x => return Err(AocError::parse(format!("unexpected command: {x}")))
+    };
+    Ok((cmd, cnt.parse()?))
+} 
+
+
+#[derive(Debug)]
+struct Rope {
+    rope: Vec<Vec2<i64>>
+}
+
+impl Rope {
+    pub fn new(len: usize) -> Self {
+        assert!(len >= 2);
+        Self {
+            rope: vec![Default::default(); len]
+        }
+    }
+
+    pub fn move_rope(&mut self, dir: Dir4) {
+        self.rope[0] += dir.delta();
+        self.fix_tail();
+    }
+
+    fn fix_tail(&mut self) {
+        for idx in 1..self.rope.len() {
+            let head = self.rope[idx-1];
+            let tail = self.rope[idx];
+            if head.chebyshev_distance(tail) <= 1 {
+                return;
+            }
+            // one step towards the head, diagonally if they are not in line
+            self.rope[idx] = tail + (head - tail).signum()
+        }
+    }
+
+    fn tail(&self) -> Vec2<i64> {
+        *self.rope.last().expect("rope has at least 2 knots")
+    }
+}
+
+struct RopeOnField {
+    tail_visited: SparseGrid<()>,
+    rope: Rope,
+}
+
+
+
+impl RopeOnField {
+    fn new(len: usize) -> Self {
+        let mut tail_visited = SparseGrid::new(());
+        let rope = Rope::new(len);
+        tail_visited.insert(rope.tail().row_col(), ());
+        RopeOnField { tail_visited, rope }
+    }
+
+    fn move_rope(&mut self, (dir, cnt): (Dir4, usize)) -> &mut Self {
+        for _ in 0..cnt {
+            self.rope.move_rope(dir);
+            // println!("{dir:?} {:?}", self.rope.rope);
+            self.tail_visited.insert(self.rope.tail().row_col(), ());
+        };
+        self
+    }
+}
+
+fn count_tail_positions(commands: &[(Dir4, usize)], len: usize) -> Result<Answer, AocError> {
+    let mut rope = RopeOnField::new(len);
+    let rope = commands.iter().copied().fold(&mut rope, RopeOnField::move_rope);
+
+    Ok(rope.tail_visited.len().into())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<(Dir4, usize)>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        Ok(crate::try_parse_line_by_line(reader, parse_command).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<Answer, AocError> {
+        count_tail_positions(commands, 2)
+    }
+
+    fn part2(commands: &Self::Input) -> Result<Answer, AocError> {
+        count_tail_positions(commands, 10)
+    }
+}
@@ -1,7 +1,6 @@
-#![allow(dead_code)]
-
 use std::io::BufRead;
 
+use crate::{AocError, Answer};
 
 #[derive(Clone, Copy)]
 enum Action {
@@ -77,7 +76,7 @@ fn parse_action(c: char) -> Action {
       'A' | 'X' => Rock,
       'B' | 'Y' => Paper,
       'C' | 'Z' => Scissors,
-       _  => panic!("Unknow action symbol {c}")
+       _  => unreachable!("symbols are validated while parsing")
     }
 }
 
@@ -86,33 +85,51 @@ fn parse_outcome(c: char) -> Outcome {
         'X' => Outcome::Lose,
         'Y' => Outcome::Draw,
         'Z' => Outcome::Win,
-        _ => panic!("Unknown outcome symbol {c}")
+        _ => unreachable!("symbols are validated while parsing")
     }
 }
 
-fn main() {
-    let infile = aoc2022::get_input_file();
-    let reader = std::io::BufReader::new(infile);
+pub struct Solver;
 
+impl crate::Solution for Solver {
+    // opponent's symbol and the second column of the strategy guide
+    type Input = Vec<(char, char)>;
 
-    // part 1
-    // let score : i64 = reader.lines().map(Result::unwrap).filter(|s| !s.is_empty()).map(
-    //     |guide| {
-    //         let mut guide = guide.chars().filter(|c| !c.is_whitespace()).map(parse_action);
-    //         let other = guide.next().expect("should be at least 2 elements");
-    //         let me =  guide.next().expect("should be at least 2 elements");
-    //         me.collide(other).score() + me.score()
-    //     }).sum();
-    
+    fn parse(reader: impl BufRead) -> Result<Self::Input, AocError> {
+        let parse_guide = |guide: &str| -> Result<_, AocError> {
+            let mut guide = guide.chars().filter(|c| !c.is_whitespace());
+            let mut next = |expected: &str| {
+                guide.next().filter(|c| expected.contains(*c)).ok_or_else(|| {
+                    AocError::parse(format!("expected one of {expected}"))
+                })
+            };
+            let other = next("ABC")?;
+            let me = next("XYZ")?;
+            Ok((other, me))
+        };
+        let guide = crate::try_parse_line_by_line(reader, |line| {
+            (!line.is_empty()).then(|| parse_guide(line)).transpose()
+        });
+        Ok(guide.filter_map(Result::transpose).collect::<Result<_, _>>()?)
+    }
 
+    fn part1(guide: &Self::Input) -> Result<Answer, AocError> {
+        let score : i64 = guide.iter().map(|&(other, me)| {
+            let other = parse_action(other);
+            let me = parse_action(me);
+            me.collide(other).score() + me.score()
+        }).sum();
 
-    let score : i64 = reader.lines().map(Result::unwrap).filter(|s| !s.is_empty()).map(
-        |guide| {
-            let mut guide = guide.chars().filter(|c| !c.is_whitespace());
-            let other = guide.next().map(parse_action).expect("should be at least 2 elements");
-            let me =  guide.next().map(parse_outcome).expect("should be at least 2 elements");
+        Ok(score.into())
+    }
+
+    fn part2(guide: &Self::Input) -> Result<Answer, AocError> {
+        let score : i64 = guide.iter().map(|&(other, me)| {
+            let other = parse_action(other);
+            let me = parse_outcome(me);
             other.guide(me).score() + me.score()
         }).sum();
 
-    println!("{score}")
-}
\ No newline at end of file
+        Ok(score.into())
+    }
+}
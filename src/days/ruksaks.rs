@@ -0,0 +1,58 @@
+use std::{io::BufRead, collections::HashSet};
+
+use crate::{AocError, Answer};
+
+
+fn priority(c: char) -> i64 {
+    match c {
+        'a'..='z' => 1 + (u64::from(c) - u64::from('a')) as i64,
+        'A'..='Z' => 27 + (u64::from(c) - u64::from('A')) as i64,
+        _ => unreachable!("items are validated while parsing")
+    }
+}
+ 
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Vec<String>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, AocError> {
+        let ruksaks = crate::try_parse_line_by_line(reader, |s| {
+            if !s.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(AocError::parse("expected only latin letters"));
+            }
+            if s.len() % 2 != 0 {
+                return Err(AocError::parse("expected two equal compartments"));
+            }
+            Ok(s.to_string())
+        });
+        Ok(ruksaks.collect::<Result<_, _>>()?)
+    }
+
+    fn part1(ruksaks: &Self::Input) -> Result<Answer, AocError> {
+        let sum : i64 = ruksaks.iter().map(|s| {
+            let len = s.len() / 2;
+            let first = &s[..len];
+            let last = &s[len..];
+            let first = HashSet::<_>::from_iter(first.chars());
+            let last = HashSet::<_>::from_iter(last.chars());
+            first.intersection(&last).map(|&c| priority(c)).sum::<i64>()
+        }).sum();
+
+        Ok(sum.into())
+    }
+
+    fn part2(ruksaks: &Self::Input) -> Result<Answer, AocError> {
+        let mut lines = ruksaks.iter();
+        let group = move || {
+            lines.by_ref().take(3).map(|s| HashSet::<_>::from_iter(s.chars())).reduce(|first, second| {
+                &first & &second
+            })
+        };
+        let groups = std::iter::from_fn(group);
+        let sum : i64 = groups.flat_map(|common| common.into_iter().map(priority)).sum();
+
+        Ok(sum.into())
+    }
+}
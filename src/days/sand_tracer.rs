@@ -0,0 +1,130 @@
+use crate::{AocError, Answer, SparseGrid, Vec2};
+
+type Vector2D = Vec2<i64>;
+
+fn line_from_to(from: Vector2D, to: Vector2D) -> impl Iterator<Item = Vector2D> {
+    let step = to - from;
+    let n = step.chebyshev();
+    let step = step.signum();
+    (0..=n).map(move |n| from + (step * n))
+}
+
+struct Line(Vec<Vector2D>);
+
+fn parse_point(s: &str) -> Result<Vector2D, AocError> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| AocError::parse("two elements are expected"))?;
+    Ok(Vec2::new(x.parse()?, y.parse()?))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Fill,
+    Sand,
+}
+
+const STEPS: [Vector2D; 3] = [
+    Vec2::new(0, 1),
+    Vec2::new(-1, 1),
+    Vec2::new(1, 1),
+];
+
+// where the sand moves from `pos`: the first free cell below
+fn next_pos(grid: &SparseGrid<Tile>, pos: Vector2D) -> Option<Vector2D> {
+    STEPS
+        .into_iter()
+        .map(|step| pos + step)
+        .find(|v| *grid.get(v.row_col()) == Tile::Air)
+}
+
+// false once the sand falls below the lowest rock
+fn simulate(grid: &mut SparseGrid<Tile>, mut start: Vector2D, lowest: i64) -> bool {
+    while start.y <= lowest {
+        if let Some(next) = next_pos(grid, start) {
+            start = next;
+        } else {
+            grid.insert(start.row_col(), Tile::Sand);
+            return true;
+        }
+    }
+    false
+}
+
+// the floor is infinite, so the sand always rests: false once it blocks the start
+fn simulate_v2(grid: &mut SparseGrid<Tile>, start: Vector2D, floor: i64) -> bool {
+    let mut cur_pos = start;
+    while let Some(next) = next_pos(grid, cur_pos).filter(|next| next.y < floor) {
+        cur_pos = next;
+    }
+    grid.insert(cur_pos.row_col(), Tile::Sand);
+    cur_pos != start
+}
+
+#[allow(dead_code)]
+fn show_grid(grid: &SparseGrid<Tile>) {
+    let tile = |tile: &Tile| match tile {
+        Tile::Air => '.',
+        Tile::Fill => '#',
+        Tile::Sand => 'o',
+    };
+    if let Some(grid) = grid.to_array() {
+        print!("{}", grid.render(tile));
+    }
+}
+
+#[derive(Clone)]
+pub struct Cave {
+    grid: SparseGrid<Tile>,
+    start: Vector2D,
+    // y of the lowest rock
+    lowest: i64,
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Cave;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        let lines: Vec<Line> = crate::try_parse_line_by_line(reader, |line| {
+            let points = line.split("->").map(str::trim).map(parse_point);
+            Ok::<_, AocError>(Line(points.collect::<Result<_, _>>()?))
+        })
+        .collect::<Result<_, _>>()?;
+        let start = Vec2::new(500, 0);
+
+        let mut grid = SparseGrid::new(Tile::Air);
+        lines
+            .iter()
+            .flat_map(|Line(line)| std::iter::zip(&line[..], &line[1..]))
+            .flat_map(|(&from, &to)| line_from_to(from, to))
+            .for_each(|v| {
+                grid.insert(v.row_col(), Tile::Fill);
+            });
+
+        let lowest = grid.bounds().map_or(start.y, |(_, (max_y, _))| max_y);
+        Ok(Cave { grid, start, lowest })
+    }
+
+    fn part1(cave: &Self::Input) -> Result<Answer, AocError> {
+        let Cave { mut grid, start, lowest } = cave.clone();
+
+        let mut sands: usize = 0;
+        while simulate(&mut grid, start, lowest) {
+            sands += 1;
+        }
+        Ok(sands.into())
+    }
+
+    fn part2(cave: &Self::Input) -> Result<Answer, AocError> {
+        let Cave { mut grid, start, lowest } = cave.clone();
+
+        let mut sands: usize = 1;
+        while simulate_v2(&mut grid, start, lowest + 2) {
+            sands += 1;
+        }
+        Ok(sands.into())
+    }
+}
@@ -0,0 +1,150 @@
+use std::io::BufRead;
+
+use crate::{AocError, Answer};
+
+type CrateStack = Vec<char>;
+
+#[derive(Debug, Default, Clone)]
+pub struct Crates {
+    table: Vec<CrateStack>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Command {
+    from: usize,
+    to: usize,
+    count: usize,
+}
+
+fn parse_command(s: &str) -> Result<Command, AocError> {
+    let mut split = s.split_whitespace();
+    let mut next = |msg: &str| split.next().ok_or_else(|| AocError::parse(msg));
+    let _move = next("move expected")?;
+    let count = next("move x expected")?.parse()?;
+    let _from = next("move x from expected")?;
+    let from = next("move x from y expected")?.parse()?;
+    let _to = next("move x from y to expected")?;
+    let to = next("move x from y to z expected")?.parse()?;
+    Ok(Command { from, to, count })
+}
+
+impl Crates {
+    fn check(&self, cmd: Command) -> Result<(), AocError> {
+        let Command { from, to, count } = cmd;
+        let stack = |idx: usize| {
+            idx.checked_sub(1)
+                .and_then(|idx| self.table.get(idx))
+                .ok_or_else(|| AocError::unsolvable(format!("no stack {idx}")))
+        };
+        stack(to)?;
+        if stack(from)?.len() < count {
+            return Err(AocError::unsolvable(format!(
+                "cannot move {count} crates from stack {from}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn exec(&mut self, cmd: Command) -> &mut Self {
+        let Command { from, to, count } = cmd;
+
+        let from = from - 1;
+        let to = to - 1;
+        for _ in 0..count {
+            let elem = self.table[from].pop();
+            self.table[to].extend(elem)
+        }
+
+        self
+    }
+
+    fn exec_preserve(&mut self, cmd: Command) -> &mut Self {
+        let Command { from, to, count } = cmd;
+
+        let from = from - 1;
+        let to = to - 1;
+
+        let mut stack_to = std::mem::take(&mut self.table[to]);
+
+        let movable = {
+            let stack = &mut self.table[from];
+            let drain_range = (stack.len() - count)..;
+            stack.drain(drain_range)
+        };
+
+        stack_to.extend(movable);
+
+        self.table[to] = stack_to;
+
+        self
+    }
+
+    fn topline(&self) -> String {
+        self.table.iter().flat_map(|stack| stack.last()).collect()
+    }
+}
+
+
+fn parse_crates_line(s: &str) -> impl Iterator<Item = Option<char>> + '_ {
+    s.as_bytes()
+        .chunks(4)
+        .map(std::str::from_utf8)
+        .map(Result::unwrap)
+        .map(str::trim)
+        .map(|elem| elem.chars().nth(1).filter(|c| c.is_ascii_alphabetic()))
+}
+
+fn rearrange(
+    (crates, commands): &(Crates, Vec<Command>),
+    exec: fn(&mut Crates, Command) -> &mut Crates,
+) -> Result<Answer, AocError> {
+    let mut crates = crates.clone();
+    commands.iter().copied().try_fold(&mut crates, |crates, cmd| {
+        crates.check(cmd)?;
+        Ok::<_, AocError>(exec(crates, cmd))
+    })?;
+    Ok(crates.topline().into())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = (Crates, Vec<Command>);
+
+    fn parse(mut reader: impl BufRead) -> Result<Self::Input, AocError> {
+        // crates drawing, followed by an empty line
+        let mut header_lines = 1;
+        let mut crates = Crates::default();
+        for line in reader.by_ref().lines() {
+            let line = line?;
+            if line.is_empty() {
+                break;
+            }
+            header_lines += 1;
+            parse_crates_line(&line).enumerate().for_each(|(idx, val)| {
+                crates
+                    .table
+                    .resize_with(crates.table.len().max(idx + 1), Default::default);
+                crates.table[idx].extend(val)
+            });
+        }
+        crates.table.iter_mut().for_each(|stack| stack.reverse());
+
+        let commands = crate::try_parse_line_by_line(reader, |line| {
+            (!line.is_empty()).then(|| parse_command(line)).transpose()
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<_, _>>()
+        .map_err(|err| crate::LineError { line: err.line + header_lines, ..err })?;
+
+        Ok((crates, commands))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        rearrange(input, Crates::exec)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        rearrange(input, Crates::exec_preserve)
+    }
+}
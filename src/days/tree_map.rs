@@ -0,0 +1,79 @@
+use std::io::BufRead;
+
+use crate::array2d::ArrayView2D;
+use crate::{AocError, Answer, Array2D, BitGrid};
+
+fn filter_peaks<'a>(iter : impl Iterator<Item = (usize, &'a u8)> + 'a) -> impl Iterator<Item = (usize, &'a u8)> + 'a {
+    let mut max = None;
+    iter.filter(move |(_, &x)| {
+        let visible = max < Some(x);
+        max = max.max(Some(x));
+        visible
+    })
+}
+
+fn compute_visibility_score<'a>(iter : impl ExactSizeIterator<Item = (usize, &'a u8)> + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+    let mut items = Vec::with_capacity(iter.size_hint().0);
+    items.resize(iter.size_hint().0, (0_usize, 0_u8));
+
+    iter.enumerate().map(move |(idx_in_line, (index, &val))| {
+        let mut prev = idx_in_line;
+        while prev > 0 && items[prev].1 < val {
+            let step = items[prev].0.max(1);
+            prev -= step
+        }
+        items[idx_in_line] = (idx_in_line - prev, val);
+        (index, items[idx_in_line].0)
+    })
+}
+
+// the map looked at from every side: each row of a view is a line of sight from its left end
+fn directions(trees: &Array2D<u8>) -> [ArrayView2D<'_, u8>; 4] {
+    let view = trees.view();
+    [view, view.rotated_cw(), view.rotated_cw().rotated_cw(), view.rotated_ccw()]
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Array2D<u8>;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, AocError> {
+        Array2D::read_grid(reader, |c| match c {
+            '0'..='9' => Ok(c as u8),
+            _ => Err(AocError::parse(format!("unexpected tree height {c:?}"))),
+        })
+    }
+
+    fn part1(trees: &Self::Input) -> Result<Answer, AocError> {
+        let mut visible = BitGrid::new(trees.shape());
+        for view in directions(trees) {
+            for x in 0..view.shape().0 {
+                for (y, _) in filter_peaks(view.row(x).enumerate()) {
+                    visible.insert(view.source_idx((x, y)));
+                }
+            }
+        }
+
+        Ok(visible.count().into())
+    }
+
+    fn part2(trees: &Self::Input) -> Result<Answer, AocError> {
+        let mut scores = trees.map(|_| 1);
+
+        for view in directions(trees) {
+            for x in 0..view.shape().0 {
+                for (y, score) in compute_visibility_score(view.row(x).enumerate()) {
+                    scores[view.source_idx((x, y))] *= score;
+                }
+            }
+        }
+
+        let maxscore = scores
+            .rows()
+            .flat_map(|r| r.iter())
+            .max()
+            .ok_or_else(|| AocError::unsolvable("expected at least one tree"))?;
+        Ok((*maxscore).into())
+    }
+}
@@ -1,4 +1,6 @@
+use std::io::BufRead;
 
+use crate::{AocError, Answer};
 
 fn first_n_unique_detector(input: &str, cnt: usize) -> Option<usize> {
     let mut counter: std::collections::HashMap<char, usize> = Default::default();
@@ -24,10 +26,28 @@ fn first_n_unique_detector(input: &str, cnt: usize) -> Option<usize> {
     })
 }
 
-fn main() {
-    let mut input = String::new();
-    let _ = std::io::stdin().read_line(&mut input);
-    let input = input.trim();
-    let pos = first_n_unique_detector(input, 14);
-    println!("{pos:?}")
-}
\ No newline at end of file
+fn find_marker(input: &str, cnt: usize) -> Result<Answer, AocError> {
+    let pos = first_n_unique_detector(input, cnt)
+        .ok_or_else(|| AocError::unsolvable("expected marker in the datastream"))?;
+    Ok(pos.into())
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = String;
+
+    fn parse(mut reader: impl BufRead) -> Result<Self::Input, AocError> {
+        let mut input = String::new();
+        reader.read_line(&mut input)?;
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        find_marker(input, 4)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        find_marker(input, 14)
+    }
+}
@@ -1,76 +1,46 @@
-struct Graph {
-    edges_to: Vec<Vec<usize>>,
-}
-
-impl Graph {
-    fn shortest_distance_matrix(&self) -> aoc2022::Array2D<usize> {
-        let vertex_cnt = self.edges_to.len();
-        let mut distances =
-            aoc2022::Array2D::from_shape_and_val((vertex_cnt, vertex_cnt), usize::MAX);
-
-        let mut queue = std::collections::VecDeque::<usize>::new();
-
-        for (start_v, distance_buffer) in (0..vertex_cnt).zip(distances.rows_mut()) {
-            queue.push_back(start_v);
-            distance_buffer[start_v] = 0;
-            while let Some(cur) = queue.pop_front() {
-                let d = distance_buffer[cur];
-                let next = self.edges_to[cur].iter().filter_map(|&next| {
-                    if distance_buffer[next] == usize::MAX {
-                        distance_buffer[next] = d + 1;
-                        Some(next)
-                    } else {
-                        None
-                    }
-                });
-                queue.extend(next);
-            }
-        }
-        distances
-    }
-}
+use crate::graph::Graph;
+use crate::{AocError, Answer, BitGrid};
 
-fn parse_line(line: &str) -> (&str, i64, impl Iterator<Item = &str> + '_) {
-    let (valve_from, to) = line.split_once(';').expect("; expected");
+fn parse_line(line: &str) -> Result<(&str, i64, impl Iterator<Item = &str> + '_), AocError> {
+    let (valve_from, to) = line
+        .split_once(';')
+        .ok_or_else(|| AocError::parse("; expected"))?;
     let valve = valve_from
         .split_whitespace()
         .nth(1)
-        .expect("expected valve name");
+        .ok_or_else(|| AocError::parse("expected valve name"))?;
     let rate: i64 = valve_from
         .split('=')
         .next_back()
-        .expect("expected rate value")
-        .parse()
-        .expect("should be int");
+        .ok_or_else(|| AocError::parse("expected rate value"))?
+        .parse()?;
     let to = to.trim();
     let to = to
         .split_whitespace()
         .skip(4)
         .map(|s| s.trim_end_matches(','));
-    (valve, rate, to)
+    Ok((valve, rate, to))
 }
 
 fn simulate(
     cur_v: usize,
-    distances: &aoc2022::Array2D<usize>,
+    distances: &crate::Array2D<usize>,
     rates: &[i64],
-    openned: &mut [bool],
+    openned: &mut BitGrid,
     time: usize,
     current_acc: i64,
     global_max: &mut i64,
 ) -> i64 {
-    let n = openned.len();
-
-    let mut next_possible: Vec<_> = (0..n)
-        .filter(|&next| !openned[next] && rates[next] > 0)
+    let mut next_possible: Vec<_> = (0..rates.len())
+        .filter(|&next| !openned.get((0, next)) && rates[next] > 0)
         .map(|to| (to, distances[(cur_v, to)] + 1))
         .filter(|(_to, cost)| *cost < time)
         .collect();
 
     let potential: i64 = rates
         .iter()
-        .zip(&*openned)
-        .filter_map(|(&val, &open)| (!open && val > 0).then_some(val))
+        .enumerate()
+        .filter_map(|(valve, &val)| (!openned.get((0, valve)) && val > 0).then_some(val))
         .sum();
 
     next_possible.sort_by_key(|(to, cost)| -((time - cost) as i64 * rates[*to]));
@@ -85,7 +55,7 @@ fn simulate(
             continue;
         }
         let gain = (time - cost) as i64 * rates[to];
-        openned[to] = true;
+        openned.insert((0, to));
         let computed_add = simulate(
             to,
             distances,
@@ -95,18 +65,19 @@ fn simulate(
             current_acc + gain,
             global_max,
         );
-        openned[to] = false;
+        openned.remove((0, to));
         *global_max = (*global_max).max(gain + computed_add + current_acc);
         computed_max = computed_max.max(gain + computed_add);
     }
     computed_max
 }
 
+#[allow(clippy::too_many_arguments)]
 fn simulate_v2(
     (cur_v1, cur_v2): (usize, usize),
-    distances: &aoc2022::Array2D<usize>,
+    distances: &crate::Array2D<usize>,
     rates: &[i64],
-    openned: &mut [bool],
+    openned: &mut BitGrid,
     non_zero: &[usize],
     (time_1, time_2): (usize, usize),
     current_acc: i64,
@@ -116,7 +87,7 @@ fn simulate_v2(
     computed: &mut usize,
 ) -> i64 {
 
-    let next_possible_v1 = non_zero.iter().copied().filter(|&next| !openned[next]);
+    let next_possible_v1 = non_zero.iter().copied().filter(|&next| !openned.get((0, next)));
 
     let next_possible_v2 = next_possible_v1.clone();
 
@@ -146,11 +117,11 @@ fn simulate_v2(
     };
 
     let compute_potential =
-        |next1: Option<(usize, usize)>, next2: Option<(usize, usize)>, visited: &[bool]| -> i64 {
+        |next1: Option<(usize, usize)>, next2: Option<(usize, usize)>, visited: &BitGrid| -> i64 {
             non_zero
                 .iter()
                 .copied()
-                .filter(|&next| !visited[next])
+                .filter(|&next| !visited.get((0, next)))
                 .map(move |to| {
                     let one = next1.map_or(0, |(from, cost)| {
                         let have_time = time_1 - cost;
@@ -190,10 +161,7 @@ fn simulate_v2(
         -compute_gain(*p1, time_1) - compute_gain(*p2, time_2)
     });
 
-    let visit = |p: Option<(usize, usize)>, visited: &mut [bool], status: bool| match p {
-        Some((to, _)) => visited[to] = status,
-        _ => {}
-    };
+    let visit = |p: Option<(usize, usize)>, visited: &mut BitGrid, status: bool| if let Some((to, _)) = p { visited.set((0, to), status) };
 
     let mut computed_max = 0;
     for (next1, next2) in next_possible {
@@ -238,7 +206,7 @@ fn simulate_v2(
             distances,
             rates,
             openned,
-            &non_zero,
+            non_zero,
             (
                 time_1 - next1.map_or(0, |p| p.1),
                 time_2 - next2.map_or(0, |p| p.1),
@@ -262,64 +230,107 @@ fn simulate_v2(
     computed_max
 }
 
-fn main() {
-    let mut mapper = std::collections::HashMap::<String, usize>::new();
-    let mut vertex_mapper = move |v: &str| -> usize {
-        let cur_cnt = mapper.len();
-        *mapper.entry(v.to_string()).or_insert(cur_cnt)
-    };
-
-    let mut graph = Graph {
-        edges_to: Default::default(),
-    };
+pub struct Volcano {
+    start: usize,
+    rates: Vec<i64>,
+    non_zero_positions: Vec<usize>,
+    shortest_distances: crate::Array2D<usize>,
+}
 
-    let mut rates = Vec::<i64>::new();
+fn parse_volcano(reader: impl std::io::BufRead) -> Result<Volcano, AocError> {
+    let mut graph = Graph::<usize>::new();
+    // by vertex index, `None` for the valves only seen at the ends of tunnels
+    let mut rates = Vec::<Option<i64>>::new();
 
-    aoc2022::parse_line_by_line(aoc2022::get_input_file(), |line| {
-        let (v, rate, next) = parse_line(line);
-        let v = (vertex_mapper)(v);
-        let next: Vec<_> = next.map(&mut vertex_mapper).collect();
-        (v, rate, next)
-    })
-    .for_each(|(v, rate, next)| {
-        if v >= graph.edges_to.len() {
-            graph.edges_to.resize_with(v + 1, Default::default);
-            rates.resize_with(v + 1, Default::default);
+    crate::try_parse_line_by_line(reader, |line| {
+        let (v, rate, next) = parse_line(line)?;
+        for next in next {
+            graph.add_edge(v, next, 1);
         }
-
-        rates[v] = rate;
-        graph.edges_to[v] = next
-    });
-
+        let v = graph.intern(v);
+        rates.resize(graph.len(), None);
+        rates[v] = Some(rate);
+        Ok::<_, AocError>(())
+    })
+    .collect::<Result<(), _>>()?;
+
+    let rates: Vec<i64> = rates
+        .into_iter()
+        .collect::<Option<_>>()
+        .ok_or_else(|| AocError::parse("tunnel leads to unknown valve"))?;
+    let start = graph
+        .index_of("AA")
+        .ok_or_else(|| AocError::parse("expected valve AA"))?;
+
+    // only the start and the valves worth opening matter, the rest is the way between them
+    let valves = graph.compressed(|v| v == start || rates[v] > 0);
+    let rates: Vec<i64> = (0..valves.len())
+        .map(|v| rates[graph.index_of(valves.name(v)).expect("kept valves are in the graph")])
+        .collect();
     let non_zero_positions: Vec<usize> = rates
         .iter()
         .enumerate()
         .filter_map(|(idx, &val)| (val > 0).then_some(idx))
         .collect();
 
-    let start = (vertex_mapper)("AA");
-    let vertices = rates.len();
-    let shortest_distances = graph.shortest_distance_matrix();
-
-    let mut openned = vec![false; vertices];
-
-    let mut global_max = 0;
-    // let answer = simulate(start, &shortest_distances, &rates, &mut openned, 30, 0, &mut global_max);
-    let mut discarded = 0;
-    let mut computed = 0;
-    let answer = simulate_v2(
-        (start, start),
-        &shortest_distances,
-        &rates,
-        &mut openned,
-        &non_zero_positions,
-        (26, 26),
-        0,
-        &mut global_max,
-        rates.iter().sum(),
-        &mut discarded,
-        &mut computed
-    );
-
-    println!("{answer} {discarded} {computed}");
+    // the walk to an unreachable valve never fits in the time left
+    let shortest_distances = valves
+        .all_pairs_shortest_paths()
+        .map(|distance| distance.unwrap_or(usize::MAX - 1));
+
+    Ok(Volcano {
+        start: valves.index_of("AA").expect("the start is kept"),
+        rates,
+        non_zero_positions,
+        shortest_distances,
+    })
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Volcano;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        parse_volcano(reader)
+    }
+
+    fn part1(volcano: &Self::Input) -> Result<Answer, AocError> {
+        let Volcano { start, rates, shortest_distances, .. } = volcano;
+
+        // a single row: one flag per valve
+        let mut openned = BitGrid::new((1, rates.len()));
+
+        let mut global_max = 0;
+        let answer = simulate(*start, shortest_distances, rates, &mut openned, 30, 0, &mut global_max);
+
+        Ok(answer.into())
+    }
+
+    fn part2(volcano: &Self::Input) -> Result<Answer, AocError> {
+        let Volcano { start, rates, non_zero_positions, shortest_distances } = volcano;
+
+        let mut openned = BitGrid::new((1, rates.len()));
+
+        let mut global_max = 0;
+        let mut discarded = 0;
+        let mut computed = 0;
+        let answer = simulate_v2(
+            (*start, *start),
+            shortest_distances,
+            rates,
+            &mut openned,
+            non_zero_positions,
+            (26, 26),
+            0,
+            &mut global_max,
+            rates.iter().sum(),
+            &mut discarded,
+            &mut computed
+        );
+
+        crate::diagnostics::report("discarded", discarded);
+        crate::diagnostics::report("computed", computed);
+        Ok(answer.into())
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{AocError, Answer, Array3D, Vec3};
+
+type Point = Vec3<i64>;
+
+fn parse_point(line: &str) -> Result<Point, AocError> {
+    let mut tokens = line.split(',');
+    let mut parse = move || -> Result<i64, AocError> {
+        let token = tokens
+            .next()
+            .ok_or_else(|| AocError::parse("expected token"))?;
+        Ok(token.trim().parse()?)
+    };
+    let x = parse()?;
+    let y = parse()?;
+    let z = parse()?;
+    Ok(Vec3::new(x, y, z))
+}
+
+// the cubes with a layer of air around them, so that the air outside is connected
+fn droplet(points: &[Point]) -> Array3D<bool> {
+    let (min, max) = points
+        .iter()
+        .fold(None, |bounds: Option<(Point, Point)>, &p| {
+            let (min, max) = bounds.unwrap_or((p, p));
+            Some((min.min(p), max.max(p)))
+        })
+        .unwrap_or_default();
+    let padding = Vec3::new(1, 1, 1);
+    let mut cubes = Array3D::from_bounds((min - padding).into(), (max + padding).into(), false);
+    for &p in points {
+        cubes[p.into()] = true;
+    }
+    cubes
+}
+
+pub struct Solver;
+
+impl crate::Solution for Solver {
+    type Input = Array3D<bool>;
+
+    fn parse(reader: impl std::io::BufRead) -> Result<Self::Input, AocError> {
+        let points: Vec<Point> =
+            crate::try_parse_line_by_line(reader, parse_point).collect::<Result<_, _>>()?;
+        Ok(droplet(&points))
+    }
+
+    fn part1(cubes: &Self::Input) -> Result<Answer, AocError> {
+        let surface_area = cubes
+            .iter_indexed()
+            .filter(|(_, &cube)| cube)
+            .flat_map(|(p, _)| cubes.neighbors6(p))
+            .filter(|&p| !cubes[p])
+            .count();
+
+        Ok(surface_area.into())
+    }
+
+    fn part2(cubes: &Self::Input) -> Result<Answer, AocError> {
+        // the padding corner is always air
+        let outside = cubes.flood_fill(cubes.origin(), |&cube| !cube);
+
+        let surface_area = cubes
+            .iter_indexed()
+            .filter(|(_, &cube)| cube)
+            .flat_map(|(p, _)| cubes.neighbors6(p))
+            .filter(|&p| outside[p])
+            .count();
+
+        Ok(surface_area.into())
+    }
+}
@@ -0,0 +1,19 @@
+use std::cell::RefCell;
+
+use crate::Answer;
+
+thread_local! {
+    static REPORTED: RefCell<Vec<(&'static str, Answer)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records a named value describing how the answer was found (e.g. search statistics).
+///
+/// The runner shows it next to the answer of the part being solved.
+pub fn report(name: &'static str, value: impl Into<Answer>) {
+    REPORTED.with(|reported| reported.borrow_mut().push((name, value.into())));
+}
+
+// everything reported since the previous call
+pub(crate) fn take() -> Vec<(&'static str, Answer)> {
+    REPORTED.with(|reported| std::mem::take(&mut *reported.borrow_mut()))
+}
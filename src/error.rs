@@ -0,0 +1,88 @@
+use crate::ShapeMismatchError;
+
+/// Failure to parse one line of the input: the cause together with the place it happened at.
+#[derive(Debug)]
+pub struct LineError<E> {
+    /// 1-based line number
+    pub line: usize,
+    pub text: String,
+    pub cause: E,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for LineError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {} (in {:?})", self.line, self.cause, self.text)
+    }
+}
+
+impl<E: std::fmt::Display + std::fmt::Debug> std::error::Error for LineError<E> {}
+
+/// Everything that can go wrong while solving a day.
+#[derive(Debug)]
+pub enum AocError {
+    Io(std::io::Error),
+    /// the input is malformed
+    Parse(String),
+    Shape(ShapeMismatchError),
+    /// the input is well-formed, but has no answer (e.g. the end of the path is unreachable)
+    Unsolvable(String),
+}
+
+impl AocError {
+    pub fn parse(msg: impl Into<String>) -> Self {
+        AocError::Parse(msg.into())
+    }
+
+    pub fn unsolvable(msg: impl Into<String>) -> Self {
+        AocError::Unsolvable(msg.into())
+    }
+}
+
+impl std::fmt::Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(err) => write!(f, "io error: {err}"),
+            AocError::Parse(msg) => write!(f, "parse error: {msg}"),
+            AocError::Shape(err) => write!(f, "shape error: {err}"),
+            AocError::Unsolvable(msg) => write!(f, "unsolvable input: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(err) => Some(err),
+            AocError::Shape(err) => Some(err),
+            AocError::Parse(_) | AocError::Unsolvable(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for AocError {
+    fn from(err: std::io::Error) -> Self {
+        AocError::Io(err)
+    }
+}
+
+impl From<ShapeMismatchError> for AocError {
+    fn from(err: ShapeMismatchError) -> Self {
+        AocError::Shape(err)
+    }
+}
+
+impl From<std::num::ParseIntError> for AocError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        AocError::Parse(err.to_string())
+    }
+}
+
+// parse errors get the location prepended, io errors are passed as is
+impl<E: Into<AocError>> From<LineError<E>> for AocError {
+    fn from(LineError { line, text, cause }: LineError<E>) -> Self {
+        match cause.into() {
+            AocError::Parse(msg) => AocError::Parse(format!("line {line}: {msg} (in {text:?})")),
+            err => err,
+        }
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::array3d::Index3;
+use crate::{Array2D, Array3D, BitGrid};
+
+/// Coordinates a bounding box can be computed for: the corners are taken component-wise.
+pub trait Coords: Copy + Eq + Hash {
+    fn min_corner(self, other: Self) -> Self;
+    fn max_corner(self, other: Self) -> Self;
+}
+
+macro_rules! impl_coords {
+    ($($t:ty),*) => {
+        $(
+            impl Coords for ($t, $t) {
+                fn min_corner(self, other: Self) -> Self {
+                    (self.0.min(other.0), self.1.min(other.1))
+                }
+
+                fn max_corner(self, other: Self) -> Self {
+                    (self.0.max(other.0), self.1.max(other.1))
+                }
+            }
+
+            impl Coords for ($t, $t, $t) {
+                fn min_corner(self, other: Self) -> Self {
+                    (self.0.min(other.0), self.1.min(other.1), self.2.min(other.2))
+                }
+
+                fn max_corner(self, other: Self) -> Self {
+                    (self.0.max(other.0), self.1.max(other.1), self.2.max(other.2))
+                }
+            }
+        )*
+    };
+}
+
+impl_coords!(usize, i64, i32);
+
+/// One connected region: the number of its cells and its inclusive bounding box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component<N> {
+    pub size: usize,
+    pub min: N,
+    pub max: N,
+}
+
+impl<N: Coords> Component<N> {
+    fn new(cell: N) -> Self {
+        Self {
+            size: 1,
+            min: cell,
+            max: cell,
+        }
+    }
+
+    fn add(&mut self, cell: N) {
+        self.size += 1;
+        self.min = self.min.min_corner(cell);
+        self.max = self.max.max_corner(cell);
+    }
+}
+
+/// Connected regions together with the label (index into `components`) of every cell in them.
+#[derive(Debug, Clone)]
+pub struct Components<N, Labels> {
+    pub labels: Labels,
+    pub components: Vec<Component<N>>,
+}
+
+/// Every cell reachable from the `starts` by steps to the `passable` `neighbors`.
+///
+/// The starts are included if they are passable themselves.
+pub fn flood_fill<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+    mut passable: impl FnMut(N) -> bool,
+) -> HashSet<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut queue: VecDeque<N> = starts
+        .into_iter()
+        .filter(|&start| passable(start))
+        .collect();
+    visited.extend(queue.iter().copied());
+    while let Some(cur) = queue.pop_front() {
+        for next in neighbors(cur) {
+            if passable(next) && visited.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+    visited
+}
+
+/// Splits the passable `cells` into the regions connected by steps to the `neighbors`.
+pub fn connected_components<N, I>(
+    cells: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+    mut passable: impl FnMut(N) -> bool,
+) -> Components<N, HashMap<N, usize>>
+where
+    N: Coords,
+    I: IntoIterator<Item = N>,
+{
+    let mut labels = HashMap::new();
+    let mut components = Vec::new();
+    let mut queue = VecDeque::new();
+    for cell in cells {
+        if labels.contains_key(&cell) || !passable(cell) {
+            continue;
+        }
+        let label = components.len();
+        let mut component = Component::new(cell);
+        labels.insert(cell, label);
+        queue.push_back(cell);
+        while let Some(cur) = queue.pop_front() {
+            for next in neighbors(cur) {
+                if !labels.contains_key(&next) && passable(next) {
+                    labels.insert(next, label);
+                    component.add(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+        components.push(component);
+    }
+    Components { labels, components }
+}
+
+impl<T> Array2D<T> {
+    /// Cells reachable from `start` by orthogonal steps through the `passable` cells.
+    pub fn flood_fill(&self, start: (usize, usize), passable: impl Fn(&T) -> bool) -> BitGrid {
+        let mut visited = BitGrid::new(self.shape());
+        if !passable(&self[start]) {
+            return visited;
+        }
+        visited.insert(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(cur) = queue.pop_front() {
+            for next in self.neighbors4(cur) {
+                if passable(&self[next]) && visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Regions of the `passable` cells connected by orthogonal steps; the other cells are unlabelled.
+    pub fn connected_components(
+        &self,
+        passable: impl Fn(&T) -> bool,
+    ) -> Components<(usize, usize), Array2D<Option<usize>>> {
+        let mut labels = Array2D::from_shape_and_val(self.shape(), None);
+        let mut components = Vec::new();
+        let mut queue = VecDeque::new();
+        for (cell, val) in self.iter_indexed() {
+            if labels[cell].is_some() || !passable(val) {
+                continue;
+            }
+            let label = components.len();
+            let mut component = Component::new(cell);
+            labels[cell] = Some(label);
+            queue.push_back(cell);
+            while let Some(cur) = queue.pop_front() {
+                for next in self.neighbors4(cur) {
+                    if labels[next].is_none() && passable(&self[next]) {
+                        labels[next] = Some(label);
+                        component.add(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        Components { labels, components }
+    }
+}
+
+impl<T> Array3D<T> {
+    /// Cells reachable from `start` by steps through the faces of the `passable` cells.
+    pub fn flood_fill(&self, start: Index3, passable: impl Fn(&T) -> bool) -> Array3D<bool> {
+        let mut visited =
+            Array3D::from_shape_and_val(self.shape(), false).with_origin(self.origin());
+        if !passable(&self[start]) {
+            return visited;
+        }
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some(cur) = queue.pop_front() {
+            for next in self.neighbors6(cur) {
+                if !visited[next] && passable(&self[next]) {
+                    visited[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        visited
+    }
+}
+
+#[test]
+fn test_grid_components() {
+    let grid = Array2D::from_grid_text("..#..\n.##..\n#...#\n", Ok::<_, crate::AocError>).unwrap();
+    let air = |&c: &char| c == '.';
+
+    assert_eq!(grid.flood_fill((0, 0), air).count(), 3);
+    assert_eq!(grid.flood_fill((0, 2), air).count(), 0);
+
+    let Components { labels, components } = grid.connected_components(air);
+    assert_eq!(
+        components,
+        [
+            Component {
+                size: 3,
+                min: (0, 0),
+                max: (1, 1)
+            },
+            Component {
+                size: 7,
+                min: (0, 1),
+                max: (2, 4)
+            },
+        ]
+    );
+    assert_eq!(labels[(2, 1)], Some(1));
+    assert_eq!(labels[(0, 2)], None);
+}
+
+#[test]
+fn test_set_components() {
+    let cubes: HashSet<(i64, i64, i64)> = [(0, 0, 0), (0, 0, 1), (5, 5, 5)].into();
+    let faces = |(x, y, z): (i64, i64, i64)| {
+        crate::array3d::FACES.map(|(dx, dy, dz)| (x + dx, y + dy, z + dz))
+    };
+
+    let filled = flood_fill([(0, 0, 0)], faces, |p| cubes.contains(&p));
+    assert_eq!(filled.len(), 2);
+
+    let mut dense = Array3D::from_bounds((0, 0, 0), (5, 5, 5), false);
+    cubes.iter().for_each(|&p| dense[p] = true);
+    let filled = dense.flood_fill((0, 0, 0), |&cube| cube);
+    assert_eq!(filled.iter_indexed().filter(|(_, &cube)| cube).count(), 2);
+
+    let Components { labels, components } =
+        connected_components(cubes.iter().copied(), faces, |p| cubes.contains(&p));
+    assert_eq!(components.len(), 2);
+    assert_eq!(labels[&(0, 0, 1)], labels[&(0, 0, 0)]);
+    let big = &components[labels[&(0, 0, 0)]];
+    assert_eq!((big.size, big.min, big.max), (2, (0, 0, 0), (0, 0, 1)));
+}
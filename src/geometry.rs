@@ -0,0 +1,303 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::array2d::GridIndex;
+
+/// Signed numbers the vectors can measure lengths with.
+pub trait Scalar:
+    Copy
+    + Ord
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(i32, i64, isize);
+
+/// Point or offset on a plane: `x` goes right and `y` goes down, like the columns and the rows of a grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Point or offset in space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// component-wise operators, scaling by a number and the element-wise helpers
+macro_rules! impl_vector {
+    ($vec:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $vec<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                $vec { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vec<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                $vec { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $vec<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {
+                $vec { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vec<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                $vec { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $vec<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $vec<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+
+        impl<T: Scalar> $vec<T> {
+            /// Every component replaced with its sign: a unit step towards the direction.
+            pub fn signum(self) -> Self {
+                $vec { $($field: self.$field.signum()),* }
+            }
+
+            pub fn abs(self) -> Self {
+                $vec { $($field: self.$field.abs()),* }
+            }
+
+            /// Component-wise minimum, e.g. the near corner of a bounding box.
+            pub fn min(self, other: Self) -> Self {
+                $vec { $($field: self.$field.min(other.$field)),* }
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                $vec { $($field: self.$field.max(other.$field)),* }
+            }
+
+            /// Sum of the absolute components: the number of orthogonal steps.
+            pub fn manhattan(self) -> T {
+                let mut len = T::default();
+                $(len = len + self.$field.abs();)*
+                len
+            }
+
+            /// The largest absolute component: the number of king moves.
+            pub fn chebyshev(self) -> T {
+                let mut len = T::default();
+                $(len = len.max(self.$field.abs());)*
+                len
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> T {
+                (self - other).manhattan()
+            }
+
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                (self - other).chebyshev()
+            }
+        }
+    };
+}
+
+impl_vector!(Vec2 { x, y });
+impl_vector!(Vec3 { x, y, z });
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// `(row, col)`, the order the grids are indexed in.
+    pub fn row_col(self) -> (T, T) {
+        (self.y, self.x)
+    }
+
+    /// `(row, col)` index of an [`crate::Array2D`] cell, `None` for negative coordinates.
+    pub fn to_index(self) -> Option<(usize, usize)>
+    where
+        T: TryInto<usize>,
+    {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+}
+
+impl Vec2<i64> {
+    /// The point of the `(row, col)` cell of an [`crate::Array2D`].
+    pub fn from_index((row, col): (usize, usize)) -> Self {
+        Self::new(col as i64, row as i64)
+    }
+}
+
+impl<T: TryInto<usize>> GridIndex for Vec2<T> {
+    fn to_unsigned(self) -> Option<(usize, usize)> {
+        self.to_index()
+    }
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for (T, T, T) {
+    fn from(Vec3 { x, y, z }: Vec3<T>) -> Self {
+        (x, y, z)
+    }
+}
+
+/// Orthogonal directions on a grid, `Up` being towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Clockwise, starting from `Up`.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn delta<T: Scalar + From<i8>>(self) -> Vec2<T> {
+        let (x, y): (i8, i8) = match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        };
+        Vec2::new(x.into(), y.into())
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Orthogonal and diagonal directions on a grid, `N` being towards the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Clockwise, starting from `N`.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn delta<T: Scalar + From<i8>>(self) -> Vec2<T> {
+        let (x, y): (i8, i8) = match self {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        };
+        Vec2::new(x.into(), y.into())
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+#[test]
+fn test_vectors() {
+    let (a, b) = (Vec2::new(3_i64, -4), Vec2::new(1, 1));
+    assert_eq!(a + b, Vec2::new(4, -3));
+    assert_eq!(a - b, Vec2::new(2, -5));
+    assert_eq!(-a * 2, Vec2::new(-6, 8));
+    assert_eq!(a.signum(), Vec2::new(1, -1));
+    assert_eq!(a.manhattan(), 7);
+    assert_eq!(a.chebyshev_distance(b), 5);
+    assert_eq!(a.min(b), Vec2::new(1, -4));
+
+    assert_eq!(a.to_index(), None);
+    assert_eq!(Vec2::new(2_i64, 5).to_index(), Some((5, 2)));
+    assert_eq!(Vec2::from_index((5, 2)), Vec2::new(2, 5));
+
+    let grid = crate::Array2D::from_grid_text("ab\ncd\n", Ok::<_, crate::AocError>).unwrap();
+    let start = Vec2::new(0_i64, 0);
+    assert_eq!(grid.get(start + Dir4::Down.delta()), Some(&'c'));
+    assert_eq!(grid.get(start + Dir4::Up.delta()), None);
+    assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+    assert_eq!(Dir8::from(Dir4::Down).delta::<i64>(), Vec2::new(0, 1));
+
+    let cube = Vec3::new(1_i64, 2, 3);
+    assert_eq!(cube.manhattan_distance(Vec3::from((0, 0, 0))), 6);
+    assert_eq!(
+        <(i64, i64, i64)>::from(cube + Vec3::new(1, 1, 1)),
+        (2, 3, 4)
+    );
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::ops::Add;
+
+use crate::Array2D;
+
+/// Directed graph with named vertices and weighted edges.
+///
+/// Names are interned: every vertex gets an index (in the order the names were first seen),
+/// which is what the edges, the distances and the other lookups use.
+#[derive(Debug, Clone, Default)]
+pub struct Graph<W> {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<W> Graph<W>
+where
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    pub fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Index of the vertex `name`, added without edges if it is new.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&idx) = self.indices.get(name) {
+            return idx;
+        }
+        let idx = self.names.len();
+        self.names.push(name.to_string());
+        self.indices.insert(name.to_string(), idx);
+        self.edges.push(Vec::new());
+        idx
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, idx: usize) -> &str {
+        &self.names[idx]
+    }
+
+    /// Number of the vertices.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Adds the edge `from -> to`, interning both names.
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) {
+        let (from, to) = (self.intern(from), self.intern(to));
+        self.edges[from].push((to, weight));
+    }
+
+    /// Edges leaving the vertex `idx` with their weights.
+    pub fn edges(&self, idx: usize) -> &[(usize, W)] {
+        &self.edges[idx]
+    }
+
+    /// Weights of the shortest paths from the vertex `from` to every vertex, `None` if unreachable.
+    pub fn shortest_paths(&self, from: usize) -> Vec<Option<W>> {
+        let next = |&cur: &usize| self.edges[cur].iter().copied();
+        let search = crate::search::dijkstra([from], next, |_| false);
+        (0..self.len()).map(|to| search.cost(&to)).collect()
+    }
+
+    /// Shortest path weights between every pair of the vertices: `(from, to)`.
+    pub fn all_pairs_shortest_paths(&self) -> Array2D<Option<W>> {
+        let n = self.len();
+        let distances = (0..n).flat_map(|from| self.shortest_paths(from));
+        Array2D::from_iter(distances, (n, n)).expect("a row for every vertex")
+    }
+
+    /// Graph of the vertices to `keep` only, joined directly by the shortest paths between them.
+    ///
+    /// The kept vertices keep their names and relative order.
+    pub fn compressed(&self, mut keep: impl FnMut(usize) -> bool) -> Self {
+        let kept: Vec<usize> = (0..self.len()).filter(|&idx| keep(idx)).collect();
+        let mut compressed = Self::new();
+        for &idx in &kept {
+            compressed.intern(self.name(idx));
+        }
+        for (from, &idx) in kept.iter().enumerate() {
+            let distances = self.shortest_paths(idx);
+            for (to, &other) in kept.iter().enumerate() {
+                if let Some(weight) = distances[other].filter(|_| to != from) {
+                    compressed.edges[from].push((to, weight));
+                }
+            }
+        }
+        compressed
+    }
+}
+
+#[test]
+fn test_graph() {
+    let mut graph = Graph::new();
+    graph.add_edge("AA", "BB", 1);
+    graph.add_edge("BB", "CC", 2);
+    graph.add_edge("AA", "CC", 5);
+    graph.add_edge("CC", "AA", 1);
+    graph.intern("DD");
+
+    assert_eq!(graph.len(), 4);
+    assert_eq!(graph.index_of("CC"), Some(2));
+    assert_eq!(graph.name(3), "DD");
+
+    let distances = graph.all_pairs_shortest_paths();
+    assert_eq!(distances[(0, 2)], Some(3));
+    assert_eq!(distances[(2, 1)], Some(2));
+    assert_eq!(distances[(1, 1)], Some(0));
+    assert_eq!(distances[(0, 3)], None);
+
+    let compressed = graph.compressed(|idx| graph.name(idx) != "BB");
+    assert_eq!(compressed.len(), 3);
+    let cc = compressed.index_of("CC").unwrap();
+    assert_eq!(
+        compressed.edges(compressed.index_of("AA").unwrap()),
+        [(cc, 3)]
+    );
+}
@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+use std::ops::{Range, Sub};
+
+/// Set of values stored as sorted half-open `[start, end)` intervals.
+///
+/// The intervals are disjoint and never touch: inserting `[1, 3)` and `[3, 5)` leaves `[1, 5)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    // start -> end
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.intervals.clear();
+    }
+
+    /// The intervals in the increasing order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Range<T>> + '_ {
+        self.intervals.iter().map(|(&start, &end)| start..end)
+    }
+
+    pub fn insert(&mut self, Range { mut start, mut end }: Range<T>) {
+        if start >= end {
+            return;
+        }
+        // the intervals overlapping or touching the new one are merged into it
+        while let Some((left, right)) = self.last_reaching(end, start) {
+            self.intervals.remove(&left);
+            start = start.min(left);
+            end = end.max(right);
+        }
+        self.intervals.insert(start, end);
+    }
+
+    // the last interval starting at or before `end` which ends at or after `start`
+    fn last_reaching(&self, end: T, start: T) -> Option<(T, T)> {
+        let (&left, &right) = self.intervals.range(..=end).next_back()?;
+        (right >= start).then_some((left, right))
+    }
+
+    pub fn remove(&mut self, Range { start, end }: Range<T>) {
+        if start >= end {
+            return;
+        }
+        // `end` itself is excluded: the interval starting there is not affected
+        while let Some((left, right)) = self
+            .intervals
+            .range(..end)
+            .next_back()
+            .filter(|&(_, &right)| right > start)
+            .map(|(&left, &right)| (left, right))
+        {
+            self.intervals.remove(&left);
+            if left < start {
+                self.intervals.insert(left, start);
+            }
+            if right > end {
+                self.intervals.insert(end, right);
+            }
+        }
+    }
+
+    // the interval containing `value`
+    fn interval_of(&self, value: T) -> Option<Range<T>> {
+        let (&start, &end) = self.intervals.range(..=value).next_back()?;
+        (value < end).then_some(start..end)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.interval_of(value).is_some()
+    }
+
+    /// Whether every value of the `range` is in the set; an empty range always is.
+    pub fn contains_range(&self, range: Range<T>) -> bool {
+        range.is_empty()
+            || self
+                .interval_of(range.start)
+                .is_some_and(|found| range.end <= found.end)
+    }
+
+    /// The smallest value of the `bounds` which is not in the set.
+    pub fn first_gap(&self, bounds: Range<T>) -> Option<T> {
+        let start = self
+            .interval_of(bounds.start)
+            .map_or(bounds.start, |found| found.end);
+        (start < bounds.end).then_some(start)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|range| union.insert(range));
+        union
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.iter().for_each(|range| difference.remove(range));
+        difference
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        for range in self.iter() {
+            let overlapping = other.intervals.range(..range.end).rev();
+            for (&left, &right) in overlapping.take_while(|&(_, &right)| right > range.start) {
+                intersection.insert(left.max(range.start)..right.min(range.end));
+            }
+        }
+        intersection
+    }
+
+    /// The values of the `bounds` which are not in the set.
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        let mut complement = Self::new();
+        complement.insert(bounds);
+        complement.difference(self)
+    }
+
+    /// Number of the values in the set.
+    pub fn covered_len(&self) -> T
+    where
+        T: Sub<Output = T> + std::iter::Sum,
+    {
+        self.iter().map(|range| range.end - range.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        iter.into_iter().for_each(|range| set.insert(range));
+        set
+    }
+}
+
+#[test]
+fn test_interval_set() {
+    let mut set = IntervalSet::new();
+    set.insert(1..5);
+    set.insert(5..6);
+    assert!(set.iter().eq(std::iter::once(1..6)));
+    set.insert(-5..10);
+    set.remove(2..5);
+    set.remove(9..10);
+    set.remove(-5..-4);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [-4..2, 5..9]);
+    assert_eq!(set.covered_len(), 10);
+    assert!(set.contains(-4) && !set.contains(2) && set.contains(8) && !set.contains(9));
+    assert!(set.contains_range(5..9) && !set.contains_range(1..6));
+    assert_eq!(set.first_gap(-4..20), Some(2));
+    assert_eq!(set.first_gap(5..9), None);
+    assert_eq!(
+        set.complement(0..12).iter().collect::<Vec<_>>(),
+        [2..5, 9..12]
+    );
+
+    set.insert(1..6);
+    assert!(set.iter().eq(std::iter::once(-4..9)));
+}
+
+#[test]
+fn test_interval_set_against_bitset() {
+    const SIZE: i64 = 64;
+    type Bits = [bool; SIZE as usize];
+
+    // xorshift: deterministic, so a failure is reproducible
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = move |limit: i64| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % limit as u64) as i64
+    };
+    let random_range = |random: &mut dyn FnMut(i64) -> i64| {
+        let (a, b) = (random(SIZE + 1), random(SIZE + 1));
+        a.min(b)..a.max(b)
+    };
+    let random_set = |random: &mut dyn FnMut(i64) -> i64| -> (IntervalSet<i64>, Bits) {
+        let (mut set, mut bits) = (IntervalSet::new(), [false; SIZE as usize]);
+        for _ in 0..random(6) {
+            let range = random_range(random);
+            bits[range.start as usize..range.end as usize].fill(true);
+            set.insert(range);
+        }
+        (set, bits)
+    };
+    let to_bits =
+        |set: &IntervalSet<i64>| -> Bits { std::array::from_fn(|x| set.contains(x as i64)) };
+
+    for _ in 0..2000 {
+        let (mut lhs, mut lhs_bits) = random_set(&mut random);
+        let (rhs, rhs_bits) = random_set(&mut random);
+
+        let range = random_range(&mut random);
+        if random(2) == 0 {
+            lhs.insert(range.clone());
+            lhs_bits[range.start as usize..range.end as usize].fill(true);
+        } else {
+            lhs.remove(range.clone());
+            lhs_bits[range.start as usize..range.end as usize].fill(false);
+        }
+        assert_eq!(to_bits(&lhs), lhs_bits);
+        // the intervals are sorted, disjoint and don't touch
+        let ranges: Vec<_> = lhs.iter().collect();
+        assert!(ranges.iter().all(|range| !range.is_empty()));
+        assert!(ranges.windows(2).all(|pair| pair[0].end < pair[1].start));
+
+        let covered = lhs_bits.iter().filter(|&&bit| bit).count() as i64;
+        assert_eq!(lhs.covered_len(), covered);
+
+        let zip = |op: fn(bool, bool) -> bool| -> Bits {
+            std::array::from_fn(|x| op(lhs_bits[x], rhs_bits[x]))
+        };
+        assert_eq!(to_bits(&lhs.union(&rhs)), zip(|a, b| a || b));
+        assert_eq!(to_bits(&lhs.intersection(&rhs)), zip(|a, b| a && b));
+        assert_eq!(to_bits(&lhs.difference(&rhs)), zip(|a, b| a && !b));
+
+        let bounds = random_range(&mut random);
+        let in_bounds = |x: usize| bounds.contains(&(x as i64));
+        let complement: Bits = std::array::from_fn(|x| in_bounds(x) && !lhs_bits[x]);
+        assert_eq!(to_bits(&lhs.complement(bounds.clone())), complement);
+
+        let first_gap = (0..SIZE as usize)
+            .find(|&x| complement[x])
+            .map(|x| x as i64);
+        assert_eq!(lhs.first_gap(bounds.clone()), first_gap);
+        let contained = (0..SIZE as usize)
+            .filter(|&x| in_bounds(x))
+            .all(|x| lhs_bits[x]);
+        assert_eq!(lhs.contains_range(bounds), contained);
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+use crate::AocError;
+
+/// Where the ledger is kept by default.
+pub const LEDGER_FILE: &str = "answers.txt";
+
+/// Accepted answers for the local inputs, one `day part answer` per line.
+///
+/// Multi-line answers are stored on one line with `\n` escaped, empty lines and
+/// lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct Ledger {
+    answers: BTreeMap<Key, String>,
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> Result<String, AocError> {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('\\') => result.push('\\'),
+            _ => return Err(AocError::parse("expected \\n or \\\\ escape")),
+        }
+    }
+    Ok(result)
+}
+
+// (day, part)
+type Key = (u32, u32);
+
+fn parse_entry(line: &str) -> Result<Option<(Key, String)>, AocError> {
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+    let mut tokens = line.splitn(3, ' ');
+    let mut next = || tokens.next().ok_or_else(|| AocError::parse("expected `day part answer`"));
+    let day = next()?.parse()?;
+    let part = next()?.parse()?;
+    let answer = unescape(next()?)?;
+    Ok(Some(((day, part), answer)))
+}
+
+impl Ledger {
+    pub fn parse(reader: impl BufRead) -> Result<Self, AocError> {
+        let answers = crate::try_parse_line_by_line(reader, parse_entry)
+            .filter_map(Result::transpose)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn write(&self, mut writer: impl std::io::Write) -> std::io::Result<()> {
+        for ((day, part), answer) in &self.answers {
+            writeln!(writer, "{day} {part} {}", escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_ledger_roundtrip() {
+    let mut ledger = Ledger::default();
+    ledger.insert(10, 2, "#..#\n\\.#.".to_string());
+    ledger.insert(1, 1, "24000".to_string());
+
+    let mut written = Vec::new();
+    ledger.write(&mut written).unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&written),
+        "1 1 24000\n10 2 #..#\\n\\\\.#.\n"
+    );
+
+    let parsed = Ledger::parse(&written[..]).unwrap();
+    assert_eq!(parsed.get(10, 2), Some("#..#\n\\.#."));
+    assert_eq!(parsed.get(1, 1), Some("24000"));
+    assert_eq!(parsed.get(1, 2), None);
+}
@@ -1,18 +1,56 @@
+pub mod array2d;
+pub mod array3d;
+pub mod bit_grid;
+pub mod cycle;
+pub mod days;
+pub mod diagnostics;
 pub mod either;
+pub mod error;
+pub mod flood;
+pub mod geometry;
+pub mod graph;
+pub mod interval_set;
 pub mod iter_ext;
+pub mod ledger;
+pub mod search;
+pub mod sparse_grid;
+
+pub use array2d::{Array2D, ShapeMismatchError};
+pub use array3d::Array3D;
+pub use bit_grid::BitGrid;
+pub use error::{AocError, LineError};
+pub use geometry::{Vec2, Vec3};
+pub use interval_set::IntervalSet;
+pub use sparse_grid::SparseGrid;
 
 use std::io::BufRead;
+use std::path::Path;
 
-pub fn get_input_file() -> std::fs::File {
-    let file = std::env::args().nth(1).expect("expected one argument");
-    std::fs::File::open(file).expect("Cannot open file with aoc input")
+/// Directory the inputs are looked up in when no explicit input is given.
+pub const INPUTS_DIR: &str = "inputs";
+
+pub fn input_file_name(day: u32) -> String {
+    format!("day{day:02}.txt")
+}
+
+/// Opens the input of the `day`: `-` stands for stdin, any other path is opened as a file,
+/// and without a path `inputs/dayNN.txt` is used.
+pub fn open_input(day: u32, path: Option<&Path>) -> Result<Box<dyn BufRead>, AocError> {
+    let path = match path {
+        Some(path) if path == Path::new("-") => return Ok(Box::new(std::io::stdin().lock())),
+        Some(path) => path.to_path_buf(),
+        None => Path::new(INPUTS_DIR).join(input_file_name(day)),
+    };
+    let file = std::fs::File::open(&path).map_err(|err| {
+        std::io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+    })?;
+    Ok(Box::new(std::io::BufReader::new(file)))
 }
 
 pub fn parse_line_by_line<T, F: for<'a> FnMut(&'a str) -> T>(
-    file: std::fs::File,
+    mut reader: impl BufRead,
     mut parse_line: F,
 ) -> impl Iterator<Item = T> {
-    let mut reader = std::io::BufReader::new(file);
     let mut buffer = String::new();
     std::iter::from_fn(move || {
         buffer.clear();
@@ -24,102 +62,104 @@ pub fn parse_line_by_line<T, F: for<'a> FnMut(&'a str) -> T>(
     })
 }
 
-#[derive(Debug, Clone)]
-pub struct Array2D<T> {
-    data: Vec<T>,
-    cols: usize,
-    rows: usize,
+// unlike `parse_line_by_line`, read errors are not swallowed: they are reported as
+// the cause of the line that failed to be read
+pub fn try_parse_line_by_line<T, E, F>(
+    mut reader: impl BufRead,
+    mut parse_line: F,
+) -> impl Iterator<Item = Result<T, LineError<E>>>
+where
+    E: From<std::io::Error>,
+    F: for<'a> FnMut(&'a str) -> Result<T, E>,
+{
+    let mut buffer = String::new();
+    let mut line = 0;
+    let mut failed = false;
+    std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        buffer.clear();
+        line += 1;
+        let parsed = match reader.read_line(&mut buffer) {
+            Ok(0) => return None,
+            Ok(_) => (parse_line)(buffer.trim()),
+            Err(err) => {
+                failed = true;
+                Err(E::from(err))
+            }
+        };
+        Some(parsed.map_err(|cause| LineError {
+            line,
+            text: buffer.trim().to_string(),
+            cause,
+        }))
+    })
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct ShapeMismatchError;
-
-impl std::fmt::Display for ShapeMismatchError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "mismathched array shape")
-    }
+/// Puzzle answer: either a number or a free-form text (e.g. letters drawn on a screen).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
 }
 
-impl std::error::Error for ShapeMismatchError {}
-
-impl<T> Array2D<T> {
-    pub fn from_iter(
-        iter: impl IntoIterator<Item = T>,
-        (rows, cols): (usize, usize),
-    ) -> Result<Self, ShapeMismatchError> {
-        let data = Vec::from_iter(iter);
-        if data.len() != rows * cols {
-            Err(ShapeMismatchError)
-        } else {
-            Ok(Array2D { data, rows, cols })
-        }
-    }
-
-    pub fn from_shape_and_val((rows, cols): (usize, usize), val: T) -> Self
-    where
-        T: Clone,
-    {
-        Self {
-            data: vec![val; rows * cols],
-            rows,
-            cols,
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(val) => write!(f, "{val}"),
+            Answer::Text(text) => write!(f, "{text}"),
         }
     }
+}
 
-    pub fn shape(&self) -> (usize, usize) {
-        (self.rows, self.cols)
+impl From<i64> for Answer {
+    fn from(val: i64) -> Self {
+        Answer::Int(val)
     }
+}
 
-    pub fn row(&self, x: usize) -> &[T] {
-        assert!(x <= self.rows);
-        let begin = x * self.cols;
-        let end = begin + self.cols;
-        &self.data[begin..end]
+impl From<usize> for Answer {
+    fn from(val: usize) -> Self {
+        Answer::Int(val as i64)
     }
+}
 
-    pub fn col(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
-        assert!(y < self.cols);
-        self.data.iter().skip(y).step_by(self.cols)
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
     }
+}
 
-    pub fn cols(
-        &self,
-    ) -> impl DoubleEndedIterator<
-        Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone,
-    > + ExactSizeIterator
-           + Clone {
-        (0..self.cols).map(move |idx| self.col(idx))
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
     }
+}
 
-    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
-        self.data.chunks(self.cols)
-    }
+/// One day of the puzzle: the input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
 
-    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + '_ {
-        self.data.chunks_mut(self.cols)
-    }
+    fn parse(input: impl BufRead) -> Result<Self::Input, AocError>;
 
-    pub fn iter_indexed(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + '_ {
-        self.data
-            .iter()
-            .enumerate()
-            .map(move |(idx, val)| ((idx / self.cols, idx % self.cols), val))
-    }
-}
+    fn part1(input: &Self::Input) -> Result<Answer, AocError>;
 
-impl<T> std::ops::Index<(usize, usize)> for Array2D<T> {
-    type Output = T;
-    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
-        assert!(x < self.rows);
-        assert!(y < self.cols);
-        &self.data[x * self.cols + y]
-    }
+    fn part2(input: &Self::Input) -> Result<Answer, AocError>;
 }
 
-impl<T> std::ops::IndexMut<(usize, usize)> for Array2D<T> {
-    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
-        assert!(x < self.rows);
-        assert!(y < self.cols);
-        &mut self.data[x * self.cols + y]
-    }
+#[test]
+fn test_line_errors() {
+    let input = "1\n2\nthree\n4\n";
+    let parsed: Vec<_> = try_parse_line_by_line(input.as_bytes(), |line| {
+        line.parse::<i64>().map_err(Box::<dyn std::error::Error>::from)
+    })
+    .collect();
+    assert_eq!(parsed.len(), 4);
+    assert_eq!(parsed[1].as_ref().ok(), Some(&2));
+
+    let err = parsed[2].as_ref().expect_err("expected error on the third line");
+    assert_eq!(err.line, 3);
+    assert_eq!(err.text, "three");
+    assert_eq!(err.to_string(), "line 3: invalid digit found in string (in \"three\")");
 }
@@ -0,0 +1,114 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Outcome of a search: the costs the nodes were reached with.
+///
+/// The search stops at the first goal it settles, so the costs of the nodes which were only
+/// seen on the frontier may be not the best ones. Without goals every reachable node is settled.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The goal the search stopped at.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Every reached node with its cost, in no particular order.
+    pub fn costs(&self) -> impl Iterator<Item = (&N, C)> + '_ {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+}
+
+/// Cheapest paths from all the `sources` at once, the successors come with the costs of the steps.
+///
+/// `C::default()` is the zero cost.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    // the heap keeps indices into `nodes`, so that the nodes don't have to be ordered
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for source in sources {
+        if search.costs.insert(source.clone(), C::default()).is_none() {
+            heap.push(Reverse((C::default(), nodes.len())));
+            nodes.push(source);
+        }
+    }
+    while let Some(Reverse((cost, idx))) = heap.pop() {
+        let cur = nodes[idx].clone();
+        // a cheaper way to the node was found after this one was queued
+        if search.costs[&cur] < cost {
+            continue;
+        }
+        if is_goal(&cur) {
+            search.goal = Some(cur);
+            break;
+        }
+        for (next, step) in successors(&cur) {
+            let next_cost = cost + step;
+            if search
+                .costs
+                .get(&next)
+                .is_some_and(|&known| known <= next_cost)
+            {
+                continue;
+            }
+            search.costs.insert(next.clone(), next_cost);
+            heap.push(Reverse((next_cost, nodes.len())));
+            nodes.push(next);
+        }
+    }
+    search
+}
+
+#[test]
+fn test_dijkstra() {
+    // digits are the costs of entering the cells, `#` are walls
+    let grid =
+        crate::Array2D::from_grid_text("1191\n1#91\n1111\n", Ok::<_, crate::AocError>).unwrap();
+    let steps = |&pos: &(usize, usize)| {
+        let grid = &grid;
+        grid.neighbors4(pos)
+            .filter_map(move |next| Some((next, grid[next].to_digit(10)?)))
+    };
+    let (start, end) = ((0, 0), (0, 3));
+
+    let weighted = dijkstra([start], steps, |&pos| pos == end);
+    assert_eq!(weighted.goal(), Some(&end));
+    assert_eq!(weighted.goal_cost(), Some(7));
+
+    let from_both = dijkstra([start, (2, 3)], steps, |_| false);
+    assert_eq!(from_both.goal(), None);
+    assert_eq!(from_both.cost(&(0, 3)), Some(2));
+    assert_eq!(from_both.cost(&(1, 1)), None);
+    assert_eq!(from_both.costs().count(), 11);
+}
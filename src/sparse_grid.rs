@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::Array2D;
+
+/// Unbounded 2D grid keyed by signed `(row, col)`, storing only the cells which were set.
+///
+/// The other cells read as the default value. The bounding box of the set cells is kept up to
+/// date, so the grid can be turned into a dense [`Array2D`] once the simulation is over.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+    default: T,
+    // inclusive (min, max) corners
+    bounds: Option<((i64, i64), (i64, i64))>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// The cell at `(x, y)`, the default one if it was never set.
+    pub fn get(&self, idx: (i64, i64)) -> &T {
+        self.cells.get(&idx).unwrap_or(&self.default)
+    }
+
+    /// Whether the cell at `(x, y)` was set.
+    pub fn contains(&self, idx: (i64, i64)) -> bool {
+        self.cells.contains_key(&idx)
+    }
+
+    pub fn insert(&mut self, idx: (i64, i64), val: T) -> Option<T> {
+        self.grow((idx, idx));
+        self.cells.insert(idx, val)
+    }
+
+    /// The cell at `(x, y)`, set to the default one first if needed.
+    pub fn get_mut(&mut self, idx: (i64, i64)) -> &mut T
+    where
+        T: Clone,
+    {
+        self.grow((idx, idx));
+        self.cells.entry(idx).or_insert_with(|| self.default.clone())
+    }
+
+    fn grow(&mut self, ((min_x, min_y), (max_x, max_y)): ((i64, i64), (i64, i64))) {
+        let ((x0, y0), (x1, y1)) = self.bounds.unwrap_or(((min_x, min_y), (max_x, max_y)));
+        self.bounds = Some(((x0.min(min_x), y0.min(min_y)), (x1.max(max_x), y1.max(max_y))));
+    }
+
+    /// Number of the cells which were set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Inclusive `(min, max)` corners of the cells which were set, `None` if there are none.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        self.bounds
+    }
+
+    /// The set cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.cells.iter().map(|(&idx, val)| (idx, val))
+    }
+
+    /// Dense copy of the bounding box: `(0, 0)` of the array is the min corner of [`SparseGrid::bounds`].
+    pub fn to_array(&self) -> Option<Array2D<T>>
+    where
+        T: Clone,
+    {
+        let ((x0, y0), (x1, y1)) = self.bounds?;
+        let shape = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+        let mut array = Array2D::from_shape_and_val(shape, self.default.clone());
+        for (&(x, y), val) in &self.cells {
+            array[((x - x0) as usize, (y - y0) as usize)] = val.clone();
+        }
+        Some(array)
+    }
+}
+
+#[test]
+fn test_sparse_grid() {
+    let mut grid = SparseGrid::new('.');
+    assert!(grid.to_array().is_none());
+
+    grid.insert((-2, 3), '#');
+    *grid.get_mut((1, -1)) = 'o';
+    assert_eq!(*grid.get((-2, 3)), '#');
+    assert_eq!(*grid.get((100, 100)), '.');
+    assert!(!grid.contains((100, 100)));
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.bounds(), Some(((-2, -1), (1, 3))));
+
+    let array = grid.to_array().unwrap();
+    assert_eq!(array.render(|&c| c).to_string(), "....#\n.....\n.....\no....\n");
+}
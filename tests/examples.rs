@@ -0,0 +1,131 @@
+// Every day solved on the example input from the puzzle description.
+
+use aoc2022::days::*;
+use aoc2022::Solution;
+
+fn check<S: Solution>(input: &str, part1: &str, part2: &str) {
+    let input = S::parse(input.as_bytes()).expect("example should be parsed");
+    let answer = S::part1(&input).expect("part 1 should be solved");
+    assert_eq!(answer.to_string(), part1, "part 1");
+    let answer = S::part2(&input).expect("part 2 should be solved");
+    assert_eq!(answer.to_string(), part2, "part 2");
+}
+
+#[test]
+fn day01_elves_calories() {
+    check::<elves_calories::Solver>(include_str!("examples/day01.txt"), "24000", "45000");
+}
+
+#[test]
+fn day02_rsp() {
+    check::<rsp::Solver>(include_str!("examples/day02.txt"), "15", "12");
+}
+
+#[test]
+fn day03_ruksaks() {
+    check::<ruksaks::Solver>(include_str!("examples/day03.txt"), "157", "70");
+}
+
+#[test]
+fn day04_camp_cleanup() {
+    check::<camp_cleanup::Solver>(include_str!("examples/day04.txt"), "2", "4");
+}
+
+#[test]
+fn day05_supply_stacks() {
+    check::<supply_stacks::Solver>(include_str!("examples/day05.txt"), "CMZ", "MCD");
+}
+
+#[test]
+fn day06_tuning_trouble() {
+    check::<tuning_trouble::Solver>(include_str!("examples/day06.txt"), "7", "19");
+}
+
+#[test]
+fn day07_fs_parse() {
+    check::<fs_parse::Solver>(include_str!("examples/day07.txt"), "95437", "24933642");
+}
+
+#[test]
+fn day08_tree_map() {
+    check::<tree_map::Solver>(include_str!("examples/day08.txt"), "21", "8");
+}
+
+#[test]
+fn day09_rope_bridge() {
+    // the larger example: the one from the first part only covers a few cells
+    check::<rope_bridge::Solver>(include_str!("examples/day09.txt"), "88", "36");
+}
+
+#[test]
+fn day10_cathode_ray() {
+    let screen = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+    check::<cathode_ray::Solver>(include_str!("examples/day10.txt"), "13140", screen);
+}
+
+#[test]
+fn day11_monkey() {
+    check::<monkey::Solver>(include_str!("examples/day11.txt"), "10605", "2713310158");
+}
+
+#[test]
+fn day12_hill_climbing() {
+    check::<hill_climbing::Solver>(include_str!("examples/day12.txt"), "31", "29");
+}
+
+#[test]
+fn day13_packets_cmp() {
+    check::<packets_cmp::Solver>(include_str!("examples/day13.txt"), "13", "140");
+}
+
+#[test]
+fn day14_sand_tracer() {
+    check::<sand_tracer::Solver>(include_str!("examples/day14.txt"), "24", "93");
+}
+
+#[test]
+fn day15_beacons() {
+    // the example asks about row 10 and the 0..=20 square instead of the real input's ones
+    let report = beacons::Solver::parse(include_str!("examples/day15.txt").as_bytes())
+        .expect("example should be parsed");
+    let answer = beacons::count_covered_at(&report, 10).expect("part 1 should be solved");
+    assert_eq!(answer.to_string(), "26", "part 1");
+    let answer = beacons::find_tuning_frequency(&report, 20).expect("part 2 should be solved");
+    assert_eq!(answer.to_string(), "56000011", "part 2");
+}
+
+#[test]
+fn day16_vaves() {
+    check::<vaves_day16::Solver>(include_str!("examples/day16.txt"), "1651", "1707");
+}
+
+#[test]
+fn day17_rocks_tetris() {
+    check::<rocks_tetris::Solver>(include_str!("examples/day17.txt"), "3068", "1514285714288");
+}
+
+#[test]
+fn day18_voxel_boulders() {
+    check::<voxel_boulders::Solver>(include_str!("examples/day18.txt"), "64", "58");
+}
+
+#[test]
+fn day19_robots() {
+    check::<robots::Solver>(include_str!("examples/day19.txt"), "33", "3472");
+}
+
+#[test]
+fn day20() {
+    check::<day20::Solver>(include_str!("examples/day20.txt"), "3", "1623178306");
+}
+
+#[test]
+fn day21_monkey_math() {
+    check::<monkey_math::Solver>(include_str!("examples/day21.txt"), "152", "301");
+}